@@ -0,0 +1,220 @@
+//! Apparent place: the geometric ephemeris corrected for light-time, annual
+//! aberration and nutation in longitude, as tabulated by almanacs.
+//!
+//! Nutation follows the IAU 1980 theory with the 63 terms kept by Meeus,
+//! *Astronomical Algorithms*, table 22.A. Light-time and aberration are
+//! applied to heliocentric rectangular vectors of date. The lunar series
+//! already gives the Moon's apparent place apart from nutation, its mean
+//! longitude having the 0.7″ of light-time folded in (Meeus, ch. 47), so
+//! the Moon only takes the nutation.
+//!
+//! All `jd` arguments are JD(TT).
+
+use serde::{Deserialize, Serialize};
+
+use crate::vsop::{heliocentric_state_of_date, rectangular_to_spherical};
+use crate::{get_geocentric, normalize_radians, Result, DEG_TO_RAD, J2000};
+
+/// Speed of light, AU per day.
+const LIGHT_SPEED_AU_PER_DAY: f64 = 173.144_632_674;
+
+/// Which corrections turn a geometric position into an apparent one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ApparentPlace {
+    /// Observe the body where it was when the light now arriving left it.
+    /// The lunar series has this built in, so it is not applied to the Moon.
+    pub light_time: bool,
+    /// Displace the direction by the Earth's orbital velocity.
+    pub annual_aberration: bool,
+    /// Refer longitudes to the true equinox of date.
+    pub nutation: bool,
+}
+
+impl ApparentPlace {
+    /// Positions straight from the ephemeris, mean equinox of date.
+    pub const GEOMETRIC: Self = Self {
+        light_time: false,
+        annual_aberration: false,
+        nutation: false,
+    };
+
+    /// All corrections, as in the astronomical almanacs.
+    pub const APPARENT: Self = Self {
+        light_time: true,
+        annual_aberration: true,
+        nutation: true,
+    };
+}
+
+/// Nutation in longitude and in obliquity (Δψ, Δε), in degrees.
+pub fn nutation(jd: f64) -> (f64, f64) {
+    let t = (jd - J2000) / 36525.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let d = 297.85036 + 445267.111480 * t - 0.0019142 * t2 + t3 / 189474.0;
+    let m = 357.52772 + 35999.050340 * t - 0.0001603 * t2 - t3 / 300000.0;
+    let m_prime = 134.96298 + 477198.867398 * t + 0.0086972 * t2 + t3 / 56250.0;
+    let f = 93.27191 + 483202.017538 * t - 0.0036825 * t2 + t3 / 327270.0;
+    let omega = 125.04452 - 1934.136261 * t + 0.0020708 * t2 + t3 / 450000.0;
+
+    let mut delta_psi = 0.0;
+    let mut delta_eps = 0.0;
+    for &[cd, cm, cm_prime, cf, co, psi, psi_t, eps, eps_t] in NUTATION.iter() {
+        let arg = (cd * d + cm * m + cm_prime * m_prime + cf * f + co * omega) * DEG_TO_RAD;
+        delta_psi += (psi + psi_t * t) * arg.sin();
+        delta_eps += (eps + eps_t * t) * arg.cos();
+    }
+
+    // table units are 0.0001"
+    (delta_psi / 36_000_000.0, delta_eps / 36_000_000.0)
+}
+
+/// Mean obliquity of the ecliptic in degrees (IAU 1980).
+pub fn mean_obliquity(jd: f64) -> f64 {
+    let t = (jd - J2000) / 36525.0;
+    23.43929111 + (-46.8150 * t - 0.00059 * t * t + 0.001813 * t * t * t) / 3600.0
+}
+
+/// Geocentric `[λ, β, Δ, λ', β', Δ']` of the Sun, Moon or a VSOP87 planet,
+/// laid out like [`get_geocentric`] but with the corrections chosen in
+/// `place` applied to λ, β and Δ.
+pub fn get_apparent(body: &str, jd: f64, place: ApparentPlace) -> Result<[f64; 6]> {
+    let mut coords = if body == "Moon" {
+        get_geocentric(body, jd)?
+    } else {
+        let (earth_p, earth_v) = heliocentric_state_of_date("Earth", jd)?;
+        let state_at = |t: f64| -> Result<([f64; 3], [f64; 3])> {
+            if body == "Sun" {
+                Ok(([0.0; 3], [0.0; 3]))
+            } else {
+                heliocentric_state_of_date(body, t)
+            }
+        };
+
+        let (mut planet_p, mut planet_v) = state_at(jd)?;
+        if place.light_time {
+            // three passes converge far below a milliarcsecond
+            for _ in 0..3 {
+                let distance = (0..3)
+                    .map(|n| (planet_p[n] - earth_p[n]).powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                (planet_p, planet_v) = state_at(jd - distance / LIGHT_SPEED_AU_PER_DAY)?;
+            }
+        }
+
+        let mut direction: [f64; 3] = std::array::from_fn(|n| planet_p[n] - earth_p[n]);
+        if place.annual_aberration {
+            let distance = direction.iter().map(|c| c * c).sum::<f64>().sqrt();
+            for n in 0..3 {
+                direction[n] += distance * earth_v[n] / LIGHT_SPEED_AU_PER_DAY;
+            }
+        }
+
+        rectangular_to_spherical(direction, std::array::from_fn(|n| planet_v[n] - earth_v[n]))
+    };
+
+    if place.nutation {
+        coords[0] = normalize_radians(coords[0] + nutation(jd).0 * DEG_TO_RAD);
+    }
+    Ok(coords)
+}
+
+/// IAU 1980 nutation series: multiples of D, M, M', F, Ω, then the sine
+/// coefficient of Δψ and its rate per century, and the cosine coefficient of
+/// Δε and its rate, all in 0.0001".
+const NUTATION: [[f64; 9]; 63] = [
+    [0.0, 0.0, 0.0, 0.0, 1.0, -171996.0, -174.2, 92025.0, 8.9],
+    [-2.0, 0.0, 0.0, 2.0, 2.0, -13187.0, -1.6, 5736.0, -3.1],
+    [0.0, 0.0, 0.0, 2.0, 2.0, -2274.0, -0.2, 977.0, -0.5],
+    [0.0, 0.0, 0.0, 0.0, 2.0, 2062.0, 0.2, -895.0, 0.5],
+    [0.0, 1.0, 0.0, 0.0, 0.0, 1426.0, -3.4, 54.0, -0.1],
+    [0.0, 0.0, 1.0, 0.0, 0.0, 712.0, 0.1, -7.0, 0.0],
+    [-2.0, 1.0, 0.0, 2.0, 2.0, -517.0, 1.2, 224.0, -0.6],
+    [0.0, 0.0, 0.0, 2.0, 1.0, -386.0, -0.4, 200.0, 0.0],
+    [0.0, 0.0, 1.0, 2.0, 2.0, -301.0, 0.0, 129.0, -0.1],
+    [-2.0, -1.0, 0.0, 2.0, 2.0, 217.0, -0.5, -95.0, 0.3],
+    [-2.0, 0.0, 1.0, 0.0, 0.0, -158.0, 0.0, 0.0, 0.0],
+    [-2.0, 0.0, 0.0, 2.0, 1.0, 129.0, 0.1, -70.0, 0.0],
+    [0.0, 0.0, -1.0, 2.0, 2.0, 123.0, 0.0, -53.0, 0.0],
+    [2.0, 0.0, 0.0, 0.0, 0.0, 63.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0, 1.0, 63.0, 0.1, -33.0, 0.0],
+    [2.0, 0.0, -1.0, 2.0, 2.0, -59.0, 0.0, 26.0, 0.0],
+    [0.0, 0.0, -1.0, 0.0, 1.0, -58.0, -0.1, 32.0, 0.0],
+    [0.0, 0.0, 1.0, 2.0, 1.0, -51.0, 0.0, 27.0, 0.0],
+    [-2.0, 0.0, 2.0, 0.0, 0.0, 48.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, -2.0, 2.0, 1.0, 46.0, 0.0, -24.0, 0.0],
+    [2.0, 0.0, 0.0, 2.0, 2.0, -38.0, 0.0, 16.0, 0.0],
+    [0.0, 0.0, 2.0, 2.0, 2.0, -31.0, 0.0, 13.0, 0.0],
+    [0.0, 0.0, 2.0, 0.0, 0.0, 29.0, 0.0, 0.0, 0.0],
+    [-2.0, 0.0, 1.0, 2.0, 2.0, 29.0, 0.0, -12.0, 0.0],
+    [0.0, 0.0, 0.0, 2.0, 0.0, 26.0, 0.0, 0.0, 0.0],
+    [-2.0, 0.0, 0.0, 2.0, 0.0, -22.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, -1.0, 2.0, 1.0, 21.0, 0.0, -10.0, 0.0],
+    [0.0, 2.0, 0.0, 0.0, 0.0, 17.0, -0.1, 0.0, 0.0],
+    [2.0, 0.0, -1.0, 0.0, 1.0, 16.0, 0.0, -8.0, 0.0],
+    [-2.0, 2.0, 0.0, 2.0, 2.0, -16.0, 0.1, 7.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0, 1.0, -15.0, 0.0, 9.0, 0.0],
+    [-2.0, 0.0, 1.0, 0.0, 1.0, -13.0, 0.0, 7.0, 0.0],
+    [0.0, -1.0, 0.0, 0.0, 1.0, -12.0, 0.0, 6.0, 0.0],
+    [0.0, 0.0, 2.0, -2.0, 0.0, 11.0, 0.0, 0.0, 0.0],
+    [2.0, 0.0, -1.0, 2.0, 1.0, -10.0, 0.0, 5.0, 0.0],
+    [2.0, 0.0, 1.0, 2.0, 2.0, -8.0, 0.0, 3.0, 0.0],
+    [0.0, 1.0, 0.0, 2.0, 2.0, 7.0, 0.0, -3.0, 0.0],
+    [-2.0, 1.0, 1.0, 0.0, 0.0, -7.0, 0.0, 0.0, 0.0],
+    [0.0, -1.0, 0.0, 2.0, 2.0, -7.0, 0.0, 3.0, 0.0],
+    [2.0, 0.0, 0.0, 2.0, 1.0, -7.0, 0.0, 3.0, 0.0],
+    [2.0, 0.0, 1.0, 0.0, 0.0, 6.0, 0.0, 0.0, 0.0],
+    [-2.0, 0.0, 2.0, 2.0, 2.0, 6.0, 0.0, -3.0, 0.0],
+    [-2.0, 0.0, 1.0, 2.0, 1.0, 6.0, 0.0, -3.0, 0.0],
+    [2.0, 0.0, -2.0, 0.0, 1.0, -6.0, 0.0, 3.0, 0.0],
+    [2.0, 0.0, 0.0, 0.0, 1.0, -6.0, 0.0, 3.0, 0.0],
+    [0.0, -1.0, 1.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0],
+    [-2.0, -1.0, 0.0, 2.0, 1.0, -5.0, 0.0, 3.0, 0.0],
+    [-2.0, 0.0, 0.0, 0.0, 1.0, -5.0, 0.0, 3.0, 0.0],
+    [0.0, 0.0, 2.0, 2.0, 1.0, -5.0, 0.0, 3.0, 0.0],
+    [-2.0, 0.0, 2.0, 0.0, 1.0, 4.0, 0.0, 0.0, 0.0],
+    [-2.0, 1.0, 0.0, 2.0, 1.0, 4.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, -2.0, 0.0, 4.0, 0.0, 0.0, 0.0],
+    [-1.0, 0.0, 1.0, 0.0, 0.0, -4.0, 0.0, 0.0, 0.0],
+    [-2.0, 1.0, 0.0, 0.0, 0.0, -4.0, 0.0, 0.0, 0.0],
+    [1.0, 0.0, 0.0, 0.0, 0.0, -4.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, -2.0, 2.0, 2.0, -3.0, 0.0, 0.0, 0.0],
+    [-1.0, -1.0, 1.0, 0.0, 0.0, -3.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 1.0, 0.0, 0.0, -3.0, 0.0, 0.0, 0.0],
+    [0.0, -1.0, 1.0, 2.0, 2.0, -3.0, 0.0, 0.0, 0.0],
+    [2.0, -1.0, -1.0, 2.0, 2.0, -3.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 3.0, 2.0, 2.0, -3.0, 0.0, 0.0, 0.0],
+    [2.0, -1.0, 0.0, 2.0, 2.0, -3.0, 0.0, 0.0, 0.0],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{normalize_degrees, RAD_TO_DEG};
+
+    #[test]
+    fn apparent_moon_matches_meeus_example_47a() {
+        // 1992 April 12, 0h TD: λ = 133.162655° plus Δψ = +0.004610°
+        let moon = get_apparent("Moon", 2448724.5, ApparentPlace::APPARENT).unwrap();
+        let lon = normalize_degrees(moon[0] * RAD_TO_DEG);
+        assert!((lon - 133.167265).abs() < 1e-5, "λ {}", lon);
+        assert!((moon[1] * RAD_TO_DEG + 3.229126).abs() < 1e-5);
+    }
+
+    #[test]
+    fn apparent_sun_matches_meeus_example_25b() {
+        // 1992 October 13, 0h TD: Θ = 199.907372°, apparent λ = 199°54′21.818″
+        // after an FK5 adjustment of -0.09″ that is not applied here
+        let jd = 2448908.5;
+        let geometric = get_apparent("Sun", jd, ApparentPlace::GEOMETRIC).unwrap();
+        assert!((geometric[0] * RAD_TO_DEG - 199.907372).abs() < 1e-5);
+        assert!((geometric[2] - 0.99760775).abs() < 1e-6);
+
+        let sun = get_apparent("Sun", jd, ApparentPlace::APPARENT).unwrap();
+        let lon = sun[0] * RAD_TO_DEG;
+        assert!((lon - 199.906060).abs() < 0.3 / 3600.0, "λ {}", lon);
+    }
+}
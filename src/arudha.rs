@@ -0,0 +1,227 @@
+//! Arudha padas (Jaimini).
+//!
+//! The pada of a house is as far from the house's lord as the lord is from
+//! the house. When that lands in the house itself or in the seventh from
+//! it, the pada moves to the tenth from there. The pada of the first house
+//! is the Arudha Lagna (AL) and that of the twelfth the Upapada (UL). Padas
+//! are counted in whole signs from the lagna of whichever divisional chart
+//! is given, with the sign lords of [`determine_house_lords`]. Scorpio and
+//! Aquarius have a second lord, Ketu and Rahu, and the stronger of the two
+//! is taken: the one outside the sign if the other occupies it, else the one
+//! with more planets beside it, else the one further on in its sign.
+
+use std::cmp::Ordering;
+
+use serde::Serialize;
+
+use crate::houses::whole_sign_cusps;
+use crate::{determine_house_lords, get_rasi_name, rasi_index, Chart, Result, VedicError};
+
+/// Arudha padas A1 to A12 of one chart, as sign names.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArudhaPadas {
+    pub chart: String,
+    pub padas: [String; 12],
+}
+
+impl ArudhaPadas {
+    /// Pada of house 1–12.
+    pub fn pada(&self, house: u8) -> Result<&str> {
+        match house {
+            1..=12 => Ok(&self.padas[house as usize - 1]),
+            _ => Err(VedicError::InvalidHouse(house as i32)),
+        }
+    }
+
+    /// Arudha Lagna, the pada of the first house.
+    pub fn arudha_lagna(&self) -> &str {
+        &self.padas[0]
+    }
+
+    /// Upapada, the pada of the twelfth house.
+    pub fn upapada(&self) -> &str {
+        &self.padas[11]
+    }
+}
+
+/// Sign index (0 = Aries) of the pada of the house in `house_sign` whose
+/// lord stands in `lord_sign`.
+pub fn arudha_pada(house_sign: usize, lord_sign: usize) -> usize {
+    let distance = (lord_sign + 12 - house_sign % 12) % 12;
+    let pada = (lord_sign + distance) % 12;
+    if pada == house_sign % 12 || pada == (house_sign + 6) % 12 {
+        (pada + 9) % 12
+    } else {
+        pada
+    }
+}
+
+/// Signs (0 = Aries) that share their lordship with a node.
+const CO_LORDS: [(usize, &str); 2] = [(7, "Ketu"), (10, "Rahu")];
+
+/// Whichever of `lord` and the node co-ruling `sign`, if any, rules the
+/// sign for its pada in `chart`. A node missing from the chart leaves the
+/// planet as lord.
+fn stronger_lord<'a>(chart: &Chart, sign: usize, lord: &'a str) -> &'a str {
+    let Some(&(_, node)) = CO_LORDS.iter().find(|&&(s, _)| s == sign) else {
+        return lord;
+    };
+    let (Some(planet), Some(co_lord)) = (chart.planets.get(lord), chart.planets.get(node)) else {
+        return lord;
+    };
+
+    let home = get_rasi_name(sign as i32);
+    match (planet.rashi == home, co_lord.rashi == home) {
+        (true, false) => return node,
+        (false, true) => return lord,
+        _ => {}
+    }
+    let company = |rashi: &str| chart.planets.values().filter(|p| p.rashi == rashi).count();
+    match company(&planet.rashi).cmp(&company(&co_lord.rashi)) {
+        Ordering::Greater => lord,
+        Ordering::Less => node,
+        // the nodes move backwards, so their progress is counted from 30°
+        Ordering::Equal if 30.0 - co_lord.degree > planet.degree => node,
+        Ordering::Equal => lord,
+    }
+}
+
+/// Arudha padas of `chart`, whose lagna falls in the sign named
+/// `lagna_rashi` (for example `compute_navamsa(asc)` for the navamsa).
+pub fn calculate_arudha_padas(chart: &Chart, lagna_rashi: &str) -> Result<ArudhaPadas> {
+    let lagna = rasi_index(lagna_rashi)
+        .ok_or_else(|| VedicError::DataError(format!("unknown rashi {}", lagna_rashi)))?;
+    let lords = determine_house_lords(&whole_sign_cusps(lagna as f64 * 30.0))?;
+
+    let mut padas: [String; 12] = Default::default();
+    for (house, lord) in lords.iter().enumerate() {
+        let lord = stronger_lord(chart, (lagna + house) % 12, lord);
+        let placed = chart.planets.get(lord).ok_or_else(|| {
+            VedicError::CalculationError(format!("{} is not placed in {}", lord, chart.name))
+        })?;
+        let lord_sign = rasi_index(&placed.rashi)
+            .ok_or_else(|| VedicError::DataError(format!("unknown rashi {}", placed.rashi)))?;
+        padas[house] = get_rasi_name(arudha_pada((lagna + house) % 12, lord_sign) as i32);
+    }
+
+    Ok(ArudhaPadas {
+        chart: chart.name.clone(),
+        padas,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::RashiPosition;
+
+    fn chart(placements: &[(&str, usize)]) -> Chart {
+        let planets = placements
+            .iter()
+            .map(|&(name, sign)| {
+                let position = RashiPosition {
+                    rashi: get_rasi_name(sign as i32),
+                    degree: 15.0,
+                    nakshatra: String::new(),
+                    pada: 1,
+                };
+                (name.to_string(), position)
+            })
+            .collect::<HashMap<_, _>>();
+        Chart {
+            name: "D1".to_string(),
+            planets,
+            houses: whole_sign_cusps(0.0),
+        }
+    }
+
+    #[test]
+    fn pada_counts_from_the_lord_as_far_as_the_lord_is_from_the_house() {
+        // Aries with Mars in Gemini, three signs on: pada in Leo
+        assert_eq!(arudha_pada(0, 2), 4);
+        // Mars in Aries itself would put the pada in Aries, the 1st: it
+        // moves to the 10th from there, Capricorn
+        assert_eq!(arudha_pada(0, 0), 9);
+        // Mars in Cancer would put the pada in Libra, the 7th: it moves to
+        // the 10th from Libra, Cancer
+        assert_eq!(arudha_pada(0, 3), 3);
+        // Mars in Libra counts back round to Aries, again the 1st
+        assert_eq!(arudha_pada(0, 6), 9);
+    }
+
+    #[test]
+    fn scorpio_and_aquarius_take_the_stronger_of_their_lords() {
+        let scorpio = get_rasi_name(7);
+        let mut chart = chart(&[
+            ("Sun", 4),
+            ("Moon", 5),
+            ("Mars", 7),
+            ("Mercury", 6),
+            ("Jupiter", 1),
+            ("Venus", 2),
+            ("Saturn", 0),
+            ("Rahu", 9),
+            ("Ketu", 3),
+        ]);
+        let move_to = |chart: &mut Chart, name: &str, sign: usize| {
+            chart.planets.get_mut(name).unwrap().rashi = get_rasi_name(sign as i32);
+        };
+
+        // Scorpio lagna. Mars in Scorpio itself yields to Ketu in Cancer,
+        // the 9th, and the 9th from Cancer is Pisces
+        let padas = calculate_arudha_padas(&chart, &scorpio).unwrap();
+        assert_eq!(padas.arudha_lagna(), get_rasi_name(11));
+
+        // Mars alone in Sagittarius, Ketu joined by the Moon: Ketu rules
+        move_to(&mut chart, "Mars", 8);
+        move_to(&mut chart, "Moon", 3);
+        let padas = calculate_arudha_padas(&chart, &scorpio).unwrap();
+        assert_eq!(padas.arudha_lagna(), get_rasi_name(11));
+        // alone like Mars, Ketu falls back on its degree, 15° from the end
+        // of Cancer against Mars at 15°, and Mars keeps the sign
+        move_to(&mut chart, "Moon", 5);
+        let padas = calculate_arudha_padas(&chart, &scorpio).unwrap();
+        assert_eq!(padas.arudha_lagna(), get_rasi_name(9));
+
+        // Aquarius, the 4th: Saturn in Aries and Rahu in Capricorn, each
+        // alone at 15°, so Saturn keeps it and the pada is Gemini
+        assert_eq!(padas.pada(4).unwrap(), get_rasi_name(2));
+        // Rahu at 10° has 20° of its backward course behind it and rules:
+        // Capricorn is the 12th from Aquarius, and Sagittarius the 12th
+        // from Capricorn
+        chart.planets.get_mut("Rahu").unwrap().degree = 10.0;
+        let padas = calculate_arudha_padas(&chart, &scorpio).unwrap();
+        assert_eq!(padas.pada(4).unwrap(), get_rasi_name(8));
+
+        // without the nodes Mars rules Scorpio alone
+        chart.planets.remove("Rahu");
+        chart.planets.remove("Ketu");
+        move_to(&mut chart, "Mars", 7);
+        let padas = calculate_arudha_padas(&chart, &scorpio).unwrap();
+        assert_eq!(padas.arudha_lagna(), get_rasi_name(4));
+    }
+
+    #[test]
+    fn arudha_lagna_and_upapada_of_a_chart() {
+        // Aries lagna with Mars in Cancer and Jupiter (lord of the 12th,
+        // Pisces) in Taurus
+        let chart = chart(&[
+            ("Sun", 4),
+            ("Moon", 5),
+            ("Mars", 3),
+            ("Mercury", 6),
+            ("Jupiter", 1),
+            ("Venus", 8),
+            ("Saturn", 10),
+        ]);
+        let padas = calculate_arudha_padas(&chart, &get_rasi_name(0)).unwrap();
+        assert_eq!(padas.arudha_lagna(), get_rasi_name(3));
+        // Pisces to Taurus is three signs, and three more is Cancer
+        assert_eq!(padas.upapada(), get_rasi_name(3));
+        // the Sun in its own Leo, the 5th: the 10th from Leo, Taurus
+        assert_eq!(padas.pada(5).unwrap(), get_rasi_name(1));
+        assert!(padas.pada(13).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Ayanamsa systems: the arc between the tropical and the sidereal zero point.
+//!
+//! Each traditional system is fixed by its value at J2000. Its sidereal zero
+//! point is then a fixed direction on the ecliptic of J2000, which the
+//! [`crate::precession`] model carries to the ecliptic of date. The true-star systems instead hold a
+//! star of the [`crate::stars`] catalogue at a fixed sidereal longitude, so
+//! they also carry the star's proper motion. A user-defined system gives its
+//! own value at a reference epoch and its own annual rate. All `jd` arguments
+//! are JD(TT).
+
+use serde::{Deserialize, Serialize};
+
+use crate::precession::{precess_from_j2000, PrecessionModel};
+use crate::stars::{star_ayanamsa, DELTA_CANCRI, SPICA};
+use crate::{normalize_degrees, AYANAMSA_2000};
+
+/// Days per Julian year.
+const DAYS_PER_YEAR: f64 = 365.25;
+
+/// Method used to place the sidereal zero point.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Ayanamsa {
+    /// Chitrapaksha, as adopted by the Indian Calendar Reform Committee.
+    #[default]
+    Lahiri,
+    /// B. V. Raman.
+    Raman,
+    /// K. S. Krishnamurti (KP).
+    Krishnamurti,
+    /// Cyril Fagan and Donald Bradley, western sidereal.
+    FaganBradley,
+    /// Sri Yukteshwar, *The Holy Science*.
+    Yukteshwar,
+    /// Spica (Chitra) at exactly 180° sidereal.
+    TrueChitra,
+    /// δ Cancri (Pushya) at exactly 106° sidereal.
+    TruePushya,
+    /// `value_deg` at `epoch_jd`, growing by `rate_arcsec_per_year`.
+    UserDefined {
+        epoch_jd: f64,
+        value_deg: f64,
+        rate_arcsec_per_year: f64,
+    },
+}
+
+impl Ayanamsa {
+    /// Ayanamsa in degrees at `jd`, with the default precession model.
+    pub fn degrees(self, jd: f64) -> f64 {
+        self.degrees_with_model(jd, PrecessionModel::default())
+    }
+
+    /// Ayanamsa in degrees at `jd`, precessing the J2000 value of the
+    /// traditional systems with `model`. User-defined systems keep their own
+    /// rate and the true-star systems their own star positions.
+    pub fn degrees_with_model(self, jd: f64, model: PrecessionModel) -> f64 {
+        let precessed = |value_2000: f64| {
+            let (longitude, _) = precess_from_j2000(value_2000, 0.0, jd, model);
+            signed_degrees(longitude)
+        };
+        match self {
+            Ayanamsa::Lahiri => precessed(AYANAMSA_2000),
+            Ayanamsa::Raman => precessed(22.410_791),
+            Ayanamsa::Krishnamurti => precessed(23.760_240),
+            Ayanamsa::FaganBradley => precessed(24.740_300),
+            Ayanamsa::Yukteshwar => precessed(22.478_803),
+            Ayanamsa::TrueChitra => star_ayanamsa(&SPICA, 180.0, jd),
+            Ayanamsa::TruePushya => star_ayanamsa(&DELTA_CANCRI, 106.0, jd),
+            Ayanamsa::UserDefined {
+                epoch_jd,
+                value_deg,
+                rate_arcsec_per_year,
+            } => value_deg + rate_arcsec_per_year / 3600.0 * (jd - epoch_jd) / DAYS_PER_YEAR,
+        }
+    }
+
+    /// Rate of change of the ayanamsa at `jd`, degrees per day.
+    pub fn rate(self, jd: f64) -> f64 {
+        self.degrees(jd + 0.5) - self.degrees(jd - 0.5)
+    }
+
+    /// Sidereal longitude in [0, 360) of a tropical longitude, both degrees.
+    pub fn to_sidereal(self, tropical_deg: f64, jd: f64) -> f64 {
+        normalize_degrees(tropical_deg - self.degrees(jd))
+    }
+}
+
+/// An angle in degrees reduced to (−180, 180], so that ayanamsas stay
+/// continuous through zero.
+pub(crate) fn signed_degrees(degrees: f64) -> f64 {
+    let d = normalize_degrees(degrees);
+    if d > 180.0 {
+        d - 360.0
+    } else {
+        d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::J2000;
+
+    #[test]
+    fn traditional_systems_keep_their_j2000_values() {
+        for (ayanamsa, value) in [
+            (Ayanamsa::Lahiri, 23.862_575),
+            (Ayanamsa::Raman, 22.410_791),
+            (Ayanamsa::Krishnamurti, 23.760_240),
+            (Ayanamsa::FaganBradley, 24.740_300),
+            (Ayanamsa::Yukteshwar, 22.478_803),
+        ] {
+            assert!(
+                (ayanamsa.degrees(J2000) - value).abs() < 1e-6,
+                "{:?}",
+                ayanamsa
+            );
+        }
+    }
+
+    #[test]
+    fn true_chitra_puts_spica_at_180_degrees() {
+        let value = Ayanamsa::TrueChitra.degrees(J2000);
+        assert!((value - 23.841_358).abs() < 1e-5, "{}", value);
+        // within 1.5′ of Lahiri, which was meant to follow Chitra
+        assert!((value - Ayanamsa::Lahiri.degrees(J2000)).abs() < 0.025);
+    }
+
+    #[test]
+    fn ayanamsa_grows_at_the_rate_of_general_precession() {
+        // IAU 2006 general precession in longitude, 5028.796″ per century
+        let per_year = Ayanamsa::Lahiri.rate(J2000) * DAYS_PER_YEAR * 3600.0;
+        assert!((per_year - 50.288).abs() < 0.001, "{}", per_year);
+        let century = Ayanamsa::Lahiri.degrees(J2000 + 36525.0) - AYANAMSA_2000;
+        assert!((century * 3600.0 - 5028.8).abs() < 2.0, "{}", century);
+    }
+
+    #[test]
+    fn user_defined_ayanamsa_grows_linearly() {
+        let ayanamsa = Ayanamsa::UserDefined {
+            epoch_jd: J2000,
+            value_deg: 23.0,
+            rate_arcsec_per_year: 36.0,
+        };
+        assert_eq!(ayanamsa.degrees(J2000), 23.0);
+        assert!((ayanamsa.degrees(J2000 + 100.0 * DAYS_PER_YEAR) - 24.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,166 @@
+//! Bhava Chalit: houses measured from their middles.
+//!
+//! In the chalit chart each bhava is centred on its madhya (middle) and runs
+//! from the sandhi (junction) halfway back to the previous madhya to the
+//! sandhi halfway on to the next. With the equal division the ascendant
+//! degree is the first madhya and every bhava spans 30°; with the Sripati
+//! division the madhyas trisect the quadrants between the ascendant and the
+//! midheaven, so bhavas widen and narrow with the latitude. A planet belongs
+//! to the bhava between whose sandhis it falls, which may differ from its
+//! whole-sign house.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ayanamsa::signed_degrees;
+use crate::houses::{equal_cusps, house_of, porphyry_cusps, sripati_cusps};
+use crate::{compute_rasi, PlanetPosition, Result};
+
+/// Division used to place the bhava madhyas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BhavaDivision {
+    /// Madhyas every 30° from the ascendant degree.
+    #[default]
+    Equal,
+    /// Madhyas trisecting each quadrant between the angles.
+    Sripati,
+}
+
+/// Place of a planet in the chalit chart.
+#[derive(Debug, Clone, Serialize)]
+pub struct BhavaPosition {
+    /// Bhava 1–12.
+    pub bhava: u8,
+    pub rashi: String,
+    pub degree: f64,
+    /// Arc from the bhava madhya, negative before it, degrees.
+    pub from_madhya: f64,
+}
+
+/// Bhava Chalit chart, laid out like [`crate::Chart`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BhavaChalit {
+    pub name: String,
+    pub division: BhavaDivision,
+    pub planets: HashMap<String, BhavaPosition>,
+    /// Sidereal madhyas of bhavas 1 to 12.
+    pub madhyas: [f64; 12],
+    /// Sidereal sandhis at which bhavas 1 to 12 begin.
+    pub sandhis: [f64; 12],
+}
+
+/// Bhava madhyas from the ascendant and midheaven, both in the same zodiac.
+/// The equal division ignores the midheaven.
+pub fn bhava_madhyas(division: BhavaDivision, asc_deg: f64, mc_deg: f64) -> [f64; 12] {
+    match division {
+        BhavaDivision::Equal => equal_cusps(asc_deg),
+        BhavaDivision::Sripati => porphyry_cusps(asc_deg, mc_deg),
+    }
+}
+
+/// Sandhis at which bhavas 1 to 12 begin: halfway from each madhya back to
+/// the previous one.
+pub fn bhava_sandhis(madhyas: &[f64; 12]) -> [f64; 12] {
+    sripati_cusps(madhyas)
+}
+
+/// Bhava (1–12) containing `longitude_deg`, given the sandhis from
+/// [`bhava_sandhis`].
+pub fn bhava_of(longitude_deg: f64, sandhis: &[f64; 12]) -> u8 {
+    house_of(longitude_deg, sandhis)
+}
+
+/// Bhava Chalit chart of `planets` for a sidereal ascendant and midheaven.
+pub fn calculate_bhava_chalit(
+    planets: &[PlanetPosition],
+    asc: f64,
+    mc: f64,
+    division: BhavaDivision,
+) -> Result<BhavaChalit> {
+    let madhyas = bhava_madhyas(division, asc, mc);
+    let sandhis = bhava_sandhis(&madhyas);
+
+    let mut planet_positions = HashMap::new();
+    for planet in planets {
+        let longitude = planet.sidereal_long_deg;
+        let bhava = bhava_of(longitude, &sandhis);
+        let madhya = madhyas[bhava as usize - 1];
+        planet_positions.insert(
+            planet.name.clone(),
+            BhavaPosition {
+                bhava,
+                rashi: compute_rasi(longitude),
+                degree: longitude.rem_euclid(30.0),
+                from_madhya: signed_degrees(longitude - madhya),
+            },
+        );
+    }
+
+    Ok(BhavaChalit {
+        name: "BHAVA CHALIT".to_string(),
+        division,
+        planets: planet_positions,
+        madhyas,
+        sandhis,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::houses::whole_sign_house;
+
+    fn planet(name: &str, longitude: f64) -> PlanetPosition {
+        PlanetPosition {
+            name: name.to_string(),
+            sidereal_long_deg: longitude,
+            latitude_deg: 0.0,
+            distance_au: 1.0,
+            speed_deg_per_day: 1.0,
+            retrograde: false,
+            stationary: false,
+        }
+    }
+
+    #[test]
+    fn sripati_sandhis_are_midpoints_of_the_porphyry_cusps() {
+        // ascendant 100°, midheaven 340°: the quadrants above the horizon
+        // are split into 40° bhavas and those below into 20° ones
+        let madhyas = bhava_madhyas(BhavaDivision::Sripati, 100.0, 340.0);
+        assert_eq!(
+            madhyas,
+            [100.0, 120.0, 140.0, 160.0, 200.0, 240.0, 280.0, 300.0, 320.0, 340.0, 20.0, 60.0]
+        );
+        assert_eq!(
+            bhava_sandhis(&madhyas),
+            [80.0, 110.0, 130.0, 150.0, 180.0, 220.0, 260.0, 290.0, 310.0, 330.0, 0.0, 40.0]
+        );
+    }
+
+    #[test]
+    fn planets_fall_between_their_sandhis() {
+        let planets = [
+            planet("Sun", 85.0),
+            planet("Moon", 105.0),
+            planet("Mars", 112.0),
+            planet("Saturn", 355.0),
+        ];
+        let chalit =
+            calculate_bhava_chalit(&planets, 100.0, 340.0, BhavaDivision::Sripati).unwrap();
+        let bhava = |name: &str| chalit.planets[name].bhava;
+
+        // 85° is in Gemini, the 12th sign from a Cancer ascendant, but
+        // past the sandhi of the first bhava
+        assert_eq!(whole_sign_house(85.0, 100.0), 12);
+        assert_eq!(bhava("Sun"), 1);
+        assert!((chalit.planets["Sun"].from_madhya + 15.0).abs() < 1e-12);
+        assert_eq!(bhava("Moon"), 1);
+        assert_eq!(bhava("Mars"), 2);
+        assert_eq!(bhava("Saturn"), 10);
+
+        let equal = calculate_bhava_chalit(&planets, 100.0, 340.0, BhavaDivision::Equal).unwrap();
+        assert_eq!(equal.sandhis[0], 85.0);
+        assert_eq!(equal.planets["Mars"].bhava, 1);
+    }
+}
@@ -0,0 +1,291 @@
+//! Calendar dates and Julian Days (Meeus, *Astronomical Algorithms*, ch. 7).
+//!
+//! Dates may be given in the Julian or the proleptic Gregorian calendar.
+//! Years use astronomical numbering: 1 BCE is year 0, 2 BCE is year −1, and
+//! so on; [`astronomical_year`] converts from the historical BCE count. The
+//! conversions hold for all dates from JD 0 (1 January 4713 BCE, Julian).
+//!
+//! [`jd_to_datetime`] turns a JD(UT) into a chrono timestamp in any zone and
+//! [`datetime_to_jd`] turns it back.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Result, VedicError};
+
+/// Calendar in which a date is expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Calendar {
+    /// Julian calendar, a leap year every four years.
+    Julian,
+    /// Gregorian calendar, extended backwards before its adoption if needed.
+    Gregorian,
+}
+
+impl Calendar {
+    /// Calendar in civil use on the given date under the papal reform:
+    /// Gregorian from 15 October 1582 onwards, Julian before.
+    pub fn civil(year: i32, month: u32, day: u32) -> Self {
+        if (year, month, day) >= (1582, 10, 15) {
+            Calendar::Gregorian
+        } else {
+            Calendar::Julian
+        }
+    }
+
+    /// Whether `year` (astronomical numbering) is a leap year.
+    pub fn is_leap_year(self, year: i32) -> bool {
+        match self {
+            Calendar::Julian => year.rem_euclid(4) == 0,
+            Calendar::Gregorian => {
+                year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+            }
+        }
+    }
+
+    /// Number of days in `month` of `year`.
+    pub fn days_in_month(self, year: i32, month: u32) -> u32 {
+        match month {
+            2 if self.is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+/// A date and time of day in a given calendar, on whatever time scale the
+/// caller uses (UT for civil times).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CalendarDate {
+    /// Astronomical year: 0 is 1 BCE, −1 is 2 BCE.
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: f64,
+    pub calendar: Calendar,
+}
+
+impl CalendarDate {
+    /// Midnight at the start of the given day.
+    pub fn new(year: i32, month: u32, day: u32, calendar: Calendar) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0.0,
+            calendar,
+        }
+    }
+
+    /// The same date with the time of day set.
+    pub fn with_time(self, hour: u32, minute: u32, second: f64) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+            ..self
+        }
+    }
+
+    /// Julian Day of this date and time; see [`julian_day`].
+    pub fn to_jd(&self) -> Result<f64> {
+        julian_day(self)
+    }
+}
+
+/// Julian Day of the Unix epoch, 1970-01-01T00:00:00Z.
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// Astronomical year number of the historical year `year_bce` BCE.
+pub fn astronomical_year(year_bce: u32) -> i32 {
+    1 - year_bce as i32
+}
+
+/// Julian Day of a calendar date, checking that the date exists in its
+/// calendar.
+pub fn julian_day(date: &CalendarDate) -> Result<f64> {
+    let CalendarDate {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        calendar,
+    } = *date;
+
+    if !(1..=12).contains(&month) {
+        return Err(VedicError::InvalidDateTime(format!(
+            "month {} out of range",
+            month
+        )));
+    }
+    if day == 0 || day > calendar.days_in_month(year, month) {
+        return Err(VedicError::InvalidDateTime(format!(
+            "day {} out of range for {}-{:02} ({:?})",
+            day, year, month, calendar
+        )));
+    }
+    // a second of 60 is a leap second
+    if hour > 23 || minute > 59 || !(0.0..61.0).contains(&second) {
+        return Err(VedicError::InvalidDateTime(format!(
+            "time {:02}:{:02}:{} out of range",
+            hour, minute, second
+        )));
+    }
+
+    let (y, m) = if month <= 2 {
+        (year as f64 - 1.0, month as f64 + 12.0)
+    } else {
+        (year as f64, month as f64)
+    };
+    let b = match calendar {
+        Calendar::Julian => 0.0,
+        Calendar::Gregorian => {
+            let a = (y / 100.0).floor();
+            2.0 - a + (a / 4.0).floor()
+        }
+    };
+    let day_fraction = (hour as f64 + minute as f64 / 60.0 + second / 3600.0) / 24.0;
+
+    Ok((365.25 * (y + 4716.0)).floor()
+        + (30.6001 * (m + 1.0)).floor()
+        + day as f64
+        + day_fraction
+        + b
+        - 1524.5)
+}
+
+/// Calendar date and time of day of a Julian Day, in the requested calendar.
+pub fn calendar_date(jd: f64, calendar: Calendar) -> Result<CalendarDate> {
+    if !jd.is_finite() || jd < 0.0 {
+        return Err(VedicError::InvalidDateTime(format!(
+            "Julian Day {} is before the start of the Julian period",
+            jd
+        )));
+    }
+
+    let mut z = (jd + 0.5).floor();
+    // time of day to the microsecond
+    let mut microseconds = ((jd + 0.5 - z) * 86400.0e6).round();
+    if microseconds >= 86400.0e6 {
+        // rounding carried into the next day
+        microseconds -= 86400.0e6;
+        z += 1.0;
+    }
+
+    let a = match calendar {
+        Calendar::Julian => z,
+        Calendar::Gregorian => {
+            let alpha = ((z - 1867216.25) / 36524.25).floor();
+            z + 1.0 + alpha - (alpha / 4.0).floor()
+        }
+    };
+    let b = a + 1524.0;
+    let c = ((b - 122.1) / 365.25).floor();
+    let d = (365.25 * c).floor();
+    let e = ((b - d) / 30.6001).floor();
+
+    let day = (b - d - (30.6001 * e).floor()) as u32;
+    let month = if e < 14.0 { e - 1.0 } else { e - 13.0 } as u32;
+    let year = if month > 2 { c - 4716.0 } else { c - 4715.0 } as i32;
+
+    let hour = (microseconds / 3600.0e6).floor();
+    let minute = ((microseconds - hour * 3600.0e6) / 60.0e6).floor();
+
+    Ok(CalendarDate {
+        year,
+        month,
+        day,
+        hour: hour as u32,
+        minute: minute as u32,
+        second: (microseconds - hour * 3600.0e6 - minute * 60.0e6) / 1.0e6,
+        calendar,
+    })
+}
+
+/// The instant `jd_ut`, a JD(UT), as a timestamp in `tz`, to the microsecond.
+pub fn jd_to_datetime<Tz: TimeZone>(jd_ut: f64, tz: &Tz) -> Result<DateTime<Tz>> {
+    let micros = ((jd_ut - UNIX_EPOCH_JD) * 86400.0e6).round();
+    if !micros.is_finite() || micros.abs() >= i64::MAX as f64 {
+        return Err(VedicError::InvalidDateTime(format!(
+            "Julian Day {} has no timestamp",
+            jd_ut
+        )));
+    }
+    DateTime::<Utc>::from_timestamp_micros(micros as i64)
+        .map(|utc| utc.with_timezone(tz))
+        .ok_or_else(|| {
+            VedicError::InvalidDateTime(format!("Julian Day {} is out of chrono's range", jd_ut))
+        })
+}
+
+/// JD(UT) of a chrono timestamp in any zone, to the microsecond.
+pub fn datetime_to_jd<Tz: TimeZone>(datetime: &DateTime<Tz>) -> f64 {
+    UNIX_EPOCH_JD + datetime.timestamp_micros() as f64 / 86400.0e6
+}
+
+/// JD(UT) of the first instant of `date` in `tz`.
+pub(crate) fn local_midnight<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> Result<f64> {
+    // where a clock change skips midnight, the day starts when it resumes
+    (0..3)
+        .find_map(|hour| {
+            let time = NaiveTime::from_hms_opt(hour, 0, 0)?;
+            tz.from_local_datetime(&date.and_time(time)).earliest()
+        })
+        .map(|datetime| datetime_to_jd(&datetime))
+        .ok_or_else(|| {
+            VedicError::InvalidDateTime(format!("{} has no local midnight in the time zone", date))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jd(year: i32, month: u32, day: u32, calendar: Calendar) -> f64 {
+        julian_day(&CalendarDate::new(year, month, day, calendar)).unwrap()
+    }
+
+    #[test]
+    fn julian_day_matches_meeus_examples() {
+        assert_eq!(jd(2000, 1, 1, Calendar::Gregorian) + 0.5, 2451545.0);
+        assert_eq!(jd(1582, 10, 15, Calendar::Gregorian), 2299160.5);
+        assert_eq!(jd(1582, 10, 4, Calendar::Julian), 2299159.5);
+        assert_eq!(jd(333, 1, 27, Calendar::Julian) + 0.5, 1842713.0);
+        assert_eq!(jd(-1000, 7, 12, Calendar::Julian) + 0.5, 1356001.0);
+        assert_eq!(jd(-4712, 1, 1, Calendar::Julian) + 0.5, 0.0);
+        assert_eq!(astronomical_year(1001), -1000);
+    }
+
+    #[test]
+    fn calendar_date_round_trips_across_bce_and_the_reform() {
+        let dates = [
+            (-4712, 1, 1, Calendar::Julian),
+            (-1000, 2, 29, Calendar::Julian),
+            (0, 12, 31, Calendar::Julian),
+            (1, 1, 1, Calendar::Julian),
+            (1582, 10, 4, Calendar::Julian),
+            (1582, 10, 15, Calendar::Gregorian),
+            (1600, 2, 29, Calendar::Gregorian),
+            (1900, 2, 28, Calendar::Gregorian),
+            (2024, 12, 31, Calendar::Gregorian),
+        ];
+        for (year, month, day, calendar) in dates {
+            let date = CalendarDate::new(year, month, day, calendar).with_time(18, 45, 30.25);
+            let back = calendar_date(julian_day(&date).unwrap(), calendar).unwrap();
+            assert_eq!(
+                (back.year, back.month, back.day, back.hour, back.minute),
+                (year, month, day, 18, 45),
+                "{:?}",
+                date
+            );
+            assert!((back.second - 30.25).abs() < 1e-3, "{:?}", back);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Panchanga of each civil day at a place, as printed in a calendar.
+//!
+//! A civil day runs from local midnight to midnight in the given time zone.
+//! Its vara, tithi, nakshatra, yoga, karana and lunar month are those in
+//! force at the sunrise falling within it, the Hindu day running on to the
+//! next sunrise.
+//! Moonrise and moonset are given only when they happen within the civil
+//! day; on about one day a month the Moon does not rise at all. Within the
+//! polar circles a day on which the Sun does not both rise and set has no
+//! Hindu day of its own and is returned as [`PanchangDay::Polar`].
+//!
+//! All times are JD(UT); [`crate::jd_to_datetime`] turns them into local
+//! timestamps.
+
+use chrono::{NaiveDate, TimeZone};
+use serde::Serialize;
+
+use crate::calendar::local_midnight;
+use crate::masa::{compute_lunar_date, LunarDate};
+use crate::muhurta::{muhurta_periods, Period};
+use crate::rise_set::{rise_set, RiseSet, RiseSetEvent, RiseSetOptions};
+use crate::transitions::{element_at, element_spans, tithi_day, ElementSpan, PanchangaElement};
+use crate::{
+    compute_rasi, sun_moon_sidereal, weekday_index, Ayanamsa, Result, VARA_NAMES, WEEKDAY_LORDS,
+};
+
+/// Panchanga of one civil day.
+#[derive(Debug, Clone, Serialize)]
+pub struct DailyPanchang {
+    pub date: NaiveDate,
+    pub sunrise: f64,
+    pub sunset: f64,
+    /// Sunrise of the following day, where this day's vara ends.
+    pub next_sunrise: f64,
+    pub moonrise: Option<f64>,
+    pub moonset: Option<f64>,
+    /// Sanskrit name of the weekday from sunrise, from [`crate::VARA_NAMES`].
+    pub vara: String,
+    pub vara_lord: String,
+    pub tithi: ElementSpan,
+    pub nakshatra: ElementSpan,
+    pub yoga: ElementSpan,
+    pub karana: ElementSpan,
+    /// Tithis that begin after this sunrise and end before the next one.
+    pub kshaya_tithis: Vec<u8>,
+    /// Whether the sunrise tithi still runs at the next sunrise.
+    pub adhika_tithi: bool,
+    /// Sidereal sign of the Sun at sunrise.
+    pub sun_rashi: String,
+    /// Sidereal sign of the Moon at sunrise.
+    pub moon_rashi: String,
+    /// Lunar month and year at sunrise.
+    pub lunar_date: LunarDate,
+    /// Auspicious and inauspicious periods from the Brahma Muhurta before
+    /// sunrise to the next sunrise, in order; see [`crate::compute_muhurtas`].
+    pub periods: Vec<Period>,
+}
+
+/// One civil day of [`daily_panchang`].
+#[derive(Debug, Clone, Serialize)]
+pub enum PanchangDay {
+    Day(Box<DailyPanchang>),
+    /// The Sun did not rise or set around `date`: `sun` is
+    /// [`RiseSet::AlwaysAbove`] in the polar day and [`RiseSet::AlwaysBelow`]
+    /// in the polar night.
+    Polar {
+        date: NaiveDate,
+        sun: RiseSet,
+    },
+}
+
+impl PanchangDay {
+    /// The civil day.
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            PanchangDay::Day(day) => day.date,
+            PanchangDay::Polar { date, .. } => *date,
+        }
+    }
+
+    /// The panchanga of the day, unless it is polar.
+    pub fn panchang(&self) -> Option<&DailyPanchang> {
+        match self {
+            PanchangDay::Day(day) => Some(day),
+            PanchangDay::Polar { .. } => None,
+        }
+    }
+}
+
+/// Panchanga of every civil day from `from` to `to`, both included, at the
+/// given place, with civil days counted in `tz` and the rising and setting
+/// of the Sun and Moon defined by `options`.
+pub fn daily_panchang<Tz: TimeZone>(
+    from: NaiveDate,
+    to: NaiveDate,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    tz: &Tz,
+    ayanamsa: Ayanamsa,
+    options: RiseSetOptions,
+) -> Result<Vec<PanchangDay>> {
+    from.iter_days()
+        .take_while(|date| *date <= to)
+        .map(|date| panchang_of_day(date, longitude_deg, latitude_deg, tz, ayanamsa, options))
+        .collect()
+}
+
+fn panchang_of_day<Tz: TimeZone>(
+    date: NaiveDate,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    tz: &Tz,
+    ayanamsa: Ayanamsa,
+    options: RiseSetOptions,
+) -> Result<PanchangDay> {
+    let midnight = local_midnight(date, tz)?;
+    let next_midnight = match date.succ_opt() {
+        Some(next) => local_midnight(next, tz)?,
+        None => midnight + 1.0,
+    };
+
+    let sun_event = |jd: f64, event: RiseSetEvent| {
+        rise_set("Sun", jd, longitude_deg, latitude_deg, event, options)
+    };
+    // the day is polar unless the Sun rises, sets and rises again, after
+    // having set the evening before
+    let rise = match sun_event(midnight, RiseSetEvent::Rise)? {
+        RiseSet::At(rise) => rise,
+        sun => return Ok(PanchangDay::Polar { date, sun }),
+    };
+    let (set, next_rise, previous_set) = match (
+        sun_event(rise, RiseSetEvent::Set)?,
+        sun_event(rise + 0.01, RiseSetEvent::Rise)?,
+        sun_event(rise - 1.0, RiseSetEvent::Set)?,
+    ) {
+        (RiseSet::At(set), RiseSet::At(next_rise), RiseSet::At(previous_set)) => {
+            (set, next_rise, previous_set)
+        }
+        (sun, RiseSet::At(_), RiseSet::At(_)) | (_, sun, RiseSet::At(_)) | (_, _, sun) => {
+            return Ok(PanchangDay::Polar { date, sun })
+        }
+    };
+    let moon_event = |event: RiseSetEvent| -> Result<Option<f64>> {
+        let outcome = rise_set(
+            "Moon",
+            midnight,
+            longitude_deg,
+            latitude_deg,
+            event,
+            options,
+        )?;
+        Ok(outcome.jd().filter(|&jd| jd < next_midnight))
+    };
+
+    let weekday = weekday_index(rise + longitude_deg / 360.0);
+    let tithis = tithi_day(rise, next_rise, ayanamsa)?;
+    let nakshatras = element_spans(PanchangaElement::Nakshatra, rise, next_rise, ayanamsa)?;
+    let (sun, moon) = sun_moon_sidereal(rise, ayanamsa);
+
+    Ok(PanchangDay::Day(Box::new(DailyPanchang {
+        date,
+        sunrise: rise,
+        sunset: set,
+        next_sunrise: next_rise,
+        moonrise: moon_event(RiseSetEvent::Rise)?,
+        moonset: moon_event(RiseSetEvent::Set)?,
+        vara: VARA_NAMES[weekday].to_string(),
+        vara_lord: WEEKDAY_LORDS[weekday].to_string(),
+        tithi: tithis.tithis[0].clone(),
+        nakshatra: nakshatras[0].clone(),
+        yoga: element_at(PanchangaElement::Yoga, rise, ayanamsa)?,
+        karana: element_at(PanchangaElement::Karana, rise, ayanamsa)?,
+        kshaya_tithis: tithis.kshaya,
+        adhika_tithi: tithis.adhika,
+        sun_rashi: compute_rasi(sun),
+        moon_rashi: compute_rasi(moon),
+        lunar_date: compute_lunar_date(rise, ayanamsa)?,
+        periods: muhurta_periods(previous_set, rise, set, next_rise, weekday, &nakshatras)?,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+
+    fn day(date: (i32, u32, u32), longitude: f64, latitude: f64, tz: &FixedOffset) -> PanchangDay {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let days = daily_panchang(
+            date,
+            date,
+            longitude,
+            latitude,
+            tz,
+            Ayanamsa::Lahiri,
+            RiseSetOptions::ALMANAC,
+        )
+        .unwrap();
+        assert_eq!(days.len(), 1);
+        days.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn vara_and_its_lord_name_the_same_weekday() {
+        let ist = FixedOffset::east_opt(19800).unwrap();
+        let monday = day((2024, 1, 1), 77.209, 28.6139, &ist);
+        let panchang = monday.panchang().unwrap();
+        assert_eq!(
+            (panchang.vara.as_str(), panchang.vara_lord.as_str()),
+            ("Soma", "Moon")
+        );
+    }
+
+    #[test]
+    fn polar_days_are_returned_without_failing() {
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        for (date, sun) in [
+            ((2024, 12, 21), RiseSet::AlwaysBelow),
+            ((2024, 6, 21), RiseSet::AlwaysAbove),
+        ] {
+            match day(date, 18.96, 69.65, &cet) {
+                PanchangDay::Polar { sun: found, .. } => assert_eq!(found, sun),
+                PanchangDay::Day(_) => panic!("the Sun rose at Tromsø on {:?}", date),
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! ΔT = TT − UT, the drift of Earth rotation time behind the uniform time
+//! scale of the ephemerides.
+//!
+//! From 1620 to the end of the table, ΔT is interpolated from observed
+//! values (Meeus, *Astronomical Algorithms*, table 10.A, extended with IERS
+//! data). Outside that span the Espenak–Meeus polynomials of the NASA
+//! *Five Millennium Canon of Solar Eclipses* are used. The polynomial is
+//! bent linearly onto the first observation over the fifty years before
+//! 1620, and after the last observation the gap to it is closed linearly by
+//! 2050, so ΔT has no jump at either end of the table.
+
+use crate::J2000;
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// First year of [`OBSERVED`]; entries follow every two years.
+const OBSERVED_START: f64 = 1620.0;
+const OBSERVED_STEP: f64 = 2.0;
+
+/// Year from which the polynomial is bent onto the first observation.
+const BLEND_START: f64 = 1570.0;
+
+/// Year by which extrapolation rejoins the long-term polynomial.
+const BLEND_END: f64 = 2050.0;
+
+/// ΔT in seconds for an instant given as JD(UT).
+pub fn delta_t(jd_ut: f64) -> f64 {
+    delta_t_for_year(2000.0 + (jd_ut - J2000) / 365.25)
+}
+
+/// ΔT in seconds for a decimal year (astronomical numbering, 0 = 1 BCE).
+pub fn delta_t_for_year(year: f64) -> f64 {
+    let last = OBSERVED_START + OBSERVED_STEP * (OBSERVED.len() - 1) as f64;
+
+    if (OBSERVED_START..=last).contains(&year) {
+        let position = (year - OBSERVED_START) / OBSERVED_STEP;
+        let index = (position.floor() as usize).min(OBSERVED.len() - 2);
+        let fraction = position - index as f64;
+        OBSERVED[index] + fraction * (OBSERVED[index + 1] - OBSERVED[index])
+    } else if year > BLEND_START && year < OBSERVED_START {
+        let offset = OBSERVED[0] - espenak_meeus(OBSERVED_START);
+        espenak_meeus(year) + offset * (year - BLEND_START) / (OBSERVED_START - BLEND_START)
+    } else if year > last && year < BLEND_END {
+        let offset = OBSERVED[OBSERVED.len() - 1] - espenak_meeus(last);
+        espenak_meeus(year) + offset * (BLEND_END - year) / (BLEND_END - last)
+    } else {
+        espenak_meeus(year)
+    }
+}
+
+/// Convert JD(UT) to JD(TT).
+pub fn ut_to_tt(jd_ut: f64) -> f64 {
+    jd_ut + delta_t(jd_ut) / SECONDS_PER_DAY
+}
+
+/// Convert JD(TT) to JD(UT).
+pub fn tt_to_ut(jd_tt: f64) -> f64 {
+    // ΔT changes by well under a second per day, so one refinement is exact
+    let guess = jd_tt - delta_t(jd_tt) / SECONDS_PER_DAY;
+    jd_tt - delta_t(guess) / SECONDS_PER_DAY
+}
+
+/// Espenak & Meeus polynomial expressions for ΔT, seconds.
+fn espenak_meeus(y: f64) -> f64 {
+    let long_term = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+
+    if y < -500.0 {
+        long_term(y)
+    } else if y < 500.0 {
+        let u = y / 100.0;
+        10583.6 - 1014.41 * u + 33.78311 * u.powi(2) - 5.952053 * u.powi(3) - 0.1798452 * u.powi(4)
+            + 0.022174192 * u.powi(5)
+            + 0.0090316521 * u.powi(6)
+    } else if y < 1600.0 {
+        let u = (y - 1000.0) / 100.0;
+        1574.2 - 556.01 * u + 71.23472 * u.powi(2) + 0.319781 * u.powi(3)
+            - 0.8503463 * u.powi(4)
+            - 0.005050998 * u.powi(5)
+            + 0.0083572073 * u.powi(6)
+    } else if y < 1700.0 {
+        let t = y - 1600.0;
+        120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+    } else if y < 1800.0 {
+        let t = y - 1700.0;
+        8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3) - t.powi(4) / 1174000.0
+    } else if y < 1860.0 {
+        let t = y - 1800.0;
+        13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+            - 0.00037436 * t.powi(4)
+            + 0.0000121272 * t.powi(5)
+            - 0.0000001699 * t.powi(6)
+            + 0.000000000875 * t.powi(7)
+    } else if y < 1900.0 {
+        let t = y - 1860.0;
+        7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3) - 0.0004473624 * t.powi(4)
+            + t.powi(5) / 233174.0
+    } else if y < 1920.0 {
+        let t = y - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if y < 1941.0 {
+        let t = y - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if y < 1961.0 {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if y < 1986.0 {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if y < 2005.0 {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if y < 2050.0 {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else if y < 2150.0 {
+        long_term(y) - 0.5628 * (2150.0 - y)
+    } else {
+        long_term(y)
+    }
+}
+
+/// Observed ΔT in seconds at the start of every second year from 1620.
+#[rustfmt::skip]
+const OBSERVED: [f64; 203] = [
+    // 1620
+    121.0, 112.0, 103.0, 95.0, 88.0, 82.0, 77.0, 72.0, 68.0, 63.0,
+    // 1640
+    60.0, 56.0, 53.0, 51.0, 48.0, 46.0, 44.0, 42.0, 40.0, 38.0,
+    // 1660
+    35.0, 33.0, 31.0, 29.0, 26.0, 24.0, 22.0, 20.0, 18.0, 16.0,
+    // 1680
+    14.0, 12.0, 11.0, 10.0, 9.0, 8.0, 7.0, 7.0, 7.0, 7.0,
+    // 1700
+    7.0, 7.0, 8.0, 8.0, 9.0, 9.0, 9.0, 9.0, 9.0, 10.0,
+    // 1720
+    10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 11.0, 11.0, 11.0,
+    // 1740
+    11.0, 11.0, 12.0, 12.0, 12.0, 12.0, 13.0, 13.0, 13.0, 14.0,
+    // 1760
+    14.0, 14.0, 14.0, 15.0, 15.0, 15.0, 15.0, 15.0, 16.0, 16.0,
+    // 1780
+    16.0, 16.0, 16.0, 16.0, 16.0, 16.0, 15.0, 15.0, 14.0, 13.0,
+    // 1800
+    13.1, 12.5, 12.2, 12.0, 12.0, 12.0, 12.0, 12.0, 12.0, 11.9,
+    // 1820
+    11.6, 11.0, 10.2, 9.2, 8.2, 7.1, 6.2, 5.6, 5.4, 5.3,
+    // 1840
+    5.4, 5.6, 5.9, 6.2, 6.5, 6.8, 7.1, 7.3, 7.5, 7.6,
+    // 1860
+    7.7, 7.3, 6.2, 5.2, 2.7, 1.4, -1.2, -2.8, -3.8, -4.8,
+    // 1880
+    -5.5, -5.3, -5.6, -5.7, -5.9, -6.0, -6.3, -6.5, -6.2, -4.7,
+    // 1900
+    -2.8, -0.1, 2.6, 5.3, 7.7, 10.4, 13.3, 16.0, 18.2, 20.2,
+    // 1920
+    21.1, 22.4, 23.5, 23.8, 24.3, 24.0, 23.9, 23.9, 23.7, 24.0,
+    // 1940
+    24.3, 25.3, 26.2, 27.3, 28.2, 29.1, 30.0, 30.7, 31.4, 32.2,
+    // 1960
+    33.1, 34.0, 35.0, 36.5, 38.3, 40.2, 42.2, 44.5, 46.5, 48.5,
+    // 1980
+    50.5, 52.2, 53.8, 54.9, 55.8, 56.9, 58.3, 60.0, 61.6, 63.0,
+    // 2000
+    63.8, 64.3, 64.6, 64.8, 65.5, 66.1, 66.6, 67.3, 68.1, 68.8,
+    // 2020
+    69.4, 69.3, 69.2,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observed_values_are_reproduced() {
+        assert!((delta_t_for_year(2000.0) - 63.8).abs() < 1e-9);
+        assert!((delta_t_for_year(1900.0) + 2.8).abs() < 1e-9);
+        assert!((delta_t_for_year(1621.0) - 116.5).abs() < 1e-9);
+        assert!((delta_t(J2000) - 63.8).abs() < 0.01);
+    }
+
+    #[test]
+    fn table_joins_the_polynomial_without_a_jump() {
+        let last = OBSERVED_START + OBSERVED_STEP * (OBSERVED.len() - 1) as f64;
+        for join in [BLEND_START, OBSERVED_START, last, BLEND_END] {
+            let before = delta_t_for_year(join - 1e-6);
+            let after = delta_t_for_year(join + 1e-6);
+            assert!(
+                (after - before).abs() < 1e-3,
+                "{}: {} then {}",
+                join,
+                before,
+                after
+            );
+        }
+    }
+
+    #[test]
+    fn ut_and_tt_round_trip() {
+        let jd_ut = 2451545.0;
+        assert!((tt_to_ut(ut_to_tt(jd_ut)) - jd_ut).abs() < 1e-9);
+    }
+}
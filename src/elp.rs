@@ -0,0 +1,246 @@
+//! Geocentric Moon from the ELP2000-82 lunar theory (Chapront-Touzé &
+//! Chapront, 1983), using the periodic terms retained by Meeus,
+//! *Astronomical Algorithms*, ch. 47: 60 terms each in longitude and
+//! distance and 60 in latitude, plus the planetary additive terms.
+//! Positions are referred to the mean ecliptic and equinox of date and are
+//! good to about 10" in longitude and 4" in latitude.
+//!
+//! All `jd` arguments are Julian Ephemeris Days, JD(TT).
+
+use crate::{DEG_TO_RAD, J2000};
+
+const DAYS_PER_CENTURY: f64 = 36525.0;
+
+/// Delaunay-style arguments of the Moon in degrees: (L', D, M, M', F).
+pub(crate) fn lunar_arguments(t: f64) -> (f64, f64, f64, f64, f64) {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let l_prime =
+        218.3164477 + 481267.88123421 * t - 0.0015786 * t2 + t3 / 538841.0 - t4 / 65194000.0;
+    let d = 297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0;
+    let m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0;
+    let m_prime =
+        134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0;
+    let f = 93.2720950 + 483202.0175233 * t - 0.0036539 * t2 - t3 / 3526000.0 + t4 / 863310000.0;
+
+    (l_prime, d, m, m_prime, f)
+}
+
+/// Longitude of the Moon's mean ascending node in degrees.
+pub(crate) fn mean_lunar_node(t: f64) -> f64 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    125.0445479 - 1934.1362891 * t + 0.0020754 * t2 + t3 / 467441.0 - t3 * t / 60616000.0
+}
+
+/// Geocentric `[λ, β, Δ]` of the Moon: ecliptic longitude and latitude of
+/// date in radians, distance between the centres of Earth and Moon in km.
+pub(crate) fn moon_spherical(jd: f64) -> [f64; 3] {
+    let t = (jd - J2000) / DAYS_PER_CENTURY;
+    let (l_prime, d, m, m_prime, f) = lunar_arguments(t);
+    let (l_prime, d, m, m_prime, f) = (
+        l_prime * DEG_TO_RAD,
+        d * DEG_TO_RAD,
+        m * DEG_TO_RAD,
+        m_prime * DEG_TO_RAD,
+        f * DEG_TO_RAD,
+    );
+
+    // The Earth's orbital eccentricity is decreasing; terms in M scale by E.
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let eccentricity_factor = |m_mult: f64| match m_mult.abs() as i32 {
+        1 => e,
+        2 => e * e,
+        _ => 1.0,
+    };
+
+    let mut sum_l = 0.0;
+    let mut sum_r = 0.0;
+    for &[cd, cm, cm_prime, cf, l_coef, r_coef] in LONGITUDE_DISTANCE.iter() {
+        let arg = cd * d + cm * m + cm_prime * m_prime + cf * f;
+        let scale = eccentricity_factor(cm);
+        sum_l += l_coef * scale * arg.sin();
+        sum_r += r_coef * scale * arg.cos();
+    }
+
+    let mut sum_b = 0.0;
+    for &[cd, cm, cm_prime, cf, b_coef] in LATITUDE.iter() {
+        let arg = cd * d + cm * m + cm_prime * m_prime + cf * f;
+        sum_b += b_coef * eccentricity_factor(cm) * arg.sin();
+    }
+
+    // Additive terms: Venus (A1), Jupiter (A2) and the flattening of the
+    // Earth (L' - F).
+    let a1 = (119.75 + 131.849 * t) * DEG_TO_RAD;
+    let a2 = (53.09 + 479264.290 * t) * DEG_TO_RAD;
+    let a3 = (313.45 + 481266.484 * t) * DEG_TO_RAD;
+    sum_l += 3958.0 * a1.sin() + 1962.0 * (l_prime - f).sin() + 318.0 * a2.sin();
+    sum_b += -2235.0 * l_prime.sin()
+        + 382.0 * a3.sin()
+        + 175.0 * (a1 - f).sin()
+        + 175.0 * (a1 + f).sin()
+        + 127.0 * (l_prime - m_prime).sin()
+        - 115.0 * (l_prime + m_prime).sin();
+
+    [
+        l_prime + sum_l * 1e-6 * DEG_TO_RAD,
+        sum_b * 1e-6 * DEG_TO_RAD,
+        385000.56 + sum_r * 1e-3,
+    ]
+}
+
+/// Periodic terms in longitude and distance: multiples of D, M, M', F, then
+/// the coefficient of sin(arg) in 1e-6 degrees and of cos(arg) in metres.
+const LONGITUDE_DISTANCE: [[f64; 6]; 60] = [
+    [0.0, 0.0, 1.0, 0.0, 6288774.0, -20905355.0],
+    [2.0, 0.0, -1.0, 0.0, 1274027.0, -3699111.0],
+    [2.0, 0.0, 0.0, 0.0, 658314.0, -2955968.0],
+    [0.0, 0.0, 2.0, 0.0, 213618.0, -569925.0],
+    [0.0, 1.0, 0.0, 0.0, -185116.0, 48888.0],
+    [0.0, 0.0, 0.0, 2.0, -114332.0, -3149.0],
+    [2.0, 0.0, -2.0, 0.0, 58793.0, 246158.0],
+    [2.0, -1.0, -1.0, 0.0, 57066.0, -152138.0],
+    [2.0, 0.0, 1.0, 0.0, 53322.0, -170733.0],
+    [2.0, -1.0, 0.0, 0.0, 45758.0, -204586.0],
+    [0.0, 1.0, -1.0, 0.0, -40923.0, -129620.0],
+    [1.0, 0.0, 0.0, 0.0, -34720.0, 108743.0],
+    [0.0, 1.0, 1.0, 0.0, -30383.0, 104755.0],
+    [2.0, 0.0, 0.0, -2.0, 15327.0, 10321.0],
+    [0.0, 0.0, 1.0, 2.0, -12528.0, 0.0],
+    [0.0, 0.0, 1.0, -2.0, 10980.0, 79661.0],
+    [4.0, 0.0, -1.0, 0.0, 10675.0, -34782.0],
+    [0.0, 0.0, 3.0, 0.0, 10034.0, -23210.0],
+    [4.0, 0.0, -2.0, 0.0, 8548.0, -21636.0],
+    [2.0, 1.0, -1.0, 0.0, -7888.0, 24208.0],
+    [2.0, 1.0, 0.0, 0.0, -6766.0, 30824.0],
+    [1.0, 0.0, -1.0, 0.0, -5163.0, -8379.0],
+    [1.0, 1.0, 0.0, 0.0, 4987.0, -16675.0],
+    [2.0, -1.0, 1.0, 0.0, 4036.0, -12831.0],
+    [2.0, 0.0, 2.0, 0.0, 3994.0, -10445.0],
+    [4.0, 0.0, 0.0, 0.0, 3861.0, -11650.0],
+    [2.0, 0.0, -3.0, 0.0, 3665.0, 14403.0],
+    [0.0, 1.0, -2.0, 0.0, -2689.0, -7003.0],
+    [2.0, 0.0, -1.0, 2.0, -2602.0, 0.0],
+    [2.0, -1.0, -2.0, 0.0, 2390.0, 10056.0],
+    [1.0, 0.0, 1.0, 0.0, -2348.0, 6322.0],
+    [2.0, -2.0, 0.0, 0.0, 2236.0, -9884.0],
+    [0.0, 1.0, 2.0, 0.0, -2120.0, 5751.0],
+    [0.0, 2.0, 0.0, 0.0, -2069.0, 0.0],
+    [2.0, -2.0, -1.0, 0.0, 2048.0, -4950.0],
+    [2.0, 0.0, 1.0, -2.0, -1773.0, 4130.0],
+    [2.0, 0.0, 0.0, 2.0, -1595.0, 0.0],
+    [4.0, -1.0, -1.0, 0.0, 1215.0, -3958.0],
+    [0.0, 0.0, 2.0, 2.0, -1110.0, 0.0],
+    [3.0, 0.0, -1.0, 0.0, -892.0, 3258.0],
+    [2.0, 1.0, 1.0, 0.0, -810.0, 2616.0],
+    [4.0, -1.0, -2.0, 0.0, 759.0, -1897.0],
+    [0.0, 2.0, -1.0, 0.0, -713.0, -2117.0],
+    [2.0, 2.0, -1.0, 0.0, -700.0, 2354.0],
+    [2.0, 1.0, -2.0, 0.0, 691.0, 0.0],
+    [2.0, -1.0, 0.0, -2.0, 596.0, 0.0],
+    [4.0, 0.0, 1.0, 0.0, 549.0, -1423.0],
+    [0.0, 0.0, 4.0, 0.0, 537.0, -1117.0],
+    [4.0, -1.0, 0.0, 0.0, 520.0, -1571.0],
+    [1.0, 0.0, -2.0, 0.0, -487.0, -1739.0],
+    [2.0, 1.0, 0.0, -2.0, -399.0, 0.0],
+    [0.0, 0.0, 2.0, -2.0, -381.0, -4421.0],
+    [1.0, 1.0, 1.0, 0.0, 351.0, 0.0],
+    [3.0, 0.0, -2.0, 0.0, -340.0, 0.0],
+    [4.0, 0.0, -3.0, 0.0, 330.0, 0.0],
+    [2.0, -1.0, 2.0, 0.0, 327.0, 0.0],
+    [0.0, 2.0, 1.0, 0.0, -323.0, 1165.0],
+    [1.0, 1.0, -1.0, 0.0, 299.0, 0.0],
+    [2.0, 0.0, 3.0, 0.0, 294.0, 0.0],
+    [2.0, 0.0, -1.0, -2.0, 0.0, 8752.0],
+];
+
+/// Periodic terms in latitude: multiples of D, M, M', F, then the
+/// coefficient of sin(arg) in 1e-6 degrees.
+const LATITUDE: [[f64; 5]; 60] = [
+    [0.0, 0.0, 0.0, 1.0, 5128122.0],
+    [0.0, 0.0, 1.0, 1.0, 280602.0],
+    [0.0, 0.0, 1.0, -1.0, 277693.0],
+    [2.0, 0.0, 0.0, -1.0, 173237.0],
+    [2.0, 0.0, -1.0, 1.0, 55413.0],
+    [2.0, 0.0, -1.0, -1.0, 46271.0],
+    [2.0, 0.0, 0.0, 1.0, 32573.0],
+    [0.0, 0.0, 2.0, 1.0, 17198.0],
+    [2.0, 0.0, 1.0, -1.0, 9266.0],
+    [0.0, 0.0, 2.0, -1.0, 8822.0],
+    [2.0, -1.0, 0.0, -1.0, 8216.0],
+    [2.0, 0.0, -2.0, -1.0, 4324.0],
+    [2.0, 0.0, 1.0, 1.0, 4200.0],
+    [2.0, 1.0, 0.0, -1.0, -3359.0],
+    [2.0, -1.0, -1.0, 1.0, 2463.0],
+    [2.0, -1.0, 0.0, 1.0, 2211.0],
+    [2.0, -1.0, -1.0, -1.0, 2065.0],
+    [0.0, 1.0, -1.0, -1.0, -1870.0],
+    [4.0, 0.0, -1.0, -1.0, 1828.0],
+    [0.0, 1.0, 0.0, 1.0, -1794.0],
+    [0.0, 0.0, 0.0, 3.0, -1749.0],
+    [0.0, 1.0, -1.0, 1.0, -1565.0],
+    [1.0, 0.0, 0.0, 1.0, -1491.0],
+    [0.0, 1.0, 1.0, 1.0, -1475.0],
+    [0.0, 1.0, 1.0, -1.0, -1410.0],
+    [0.0, 1.0, 0.0, -1.0, -1344.0],
+    [1.0, 0.0, 0.0, -1.0, -1335.0],
+    [0.0, 0.0, 3.0, 1.0, 1107.0],
+    [4.0, 0.0, 0.0, -1.0, 1021.0],
+    [4.0, 0.0, -1.0, 1.0, 833.0],
+    [0.0, 0.0, 1.0, -3.0, 777.0],
+    [4.0, 0.0, -2.0, 1.0, 671.0],
+    [2.0, 0.0, 0.0, -3.0, 607.0],
+    [2.0, 0.0, 2.0, -1.0, 596.0],
+    [2.0, -1.0, 1.0, -1.0, 491.0],
+    [2.0, 0.0, -2.0, 1.0, -451.0],
+    [0.0, 0.0, 3.0, -1.0, 439.0],
+    [2.0, 0.0, 2.0, 1.0, 422.0],
+    [2.0, 0.0, -3.0, -1.0, 421.0],
+    [2.0, 1.0, -1.0, 1.0, -366.0],
+    [2.0, 1.0, 0.0, 1.0, -351.0],
+    [4.0, 0.0, 0.0, 1.0, 331.0],
+    [2.0, -1.0, 1.0, 1.0, 315.0],
+    [2.0, -2.0, 0.0, -1.0, 302.0],
+    [0.0, 0.0, 1.0, 3.0, -283.0],
+    [2.0, 1.0, 1.0, -1.0, -229.0],
+    [1.0, 1.0, 0.0, -1.0, 223.0],
+    [1.0, 1.0, 0.0, 1.0, 223.0],
+    [0.0, 1.0, -2.0, -1.0, -220.0],
+    [2.0, 1.0, -1.0, -1.0, -220.0],
+    [1.0, 0.0, 1.0, 1.0, -185.0],
+    [2.0, -1.0, -2.0, -1.0, 181.0],
+    [0.0, 1.0, 2.0, 1.0, -177.0],
+    [4.0, 0.0, -2.0, -1.0, 176.0],
+    [4.0, -1.0, -1.0, -1.0, 166.0],
+    [1.0, 0.0, 1.0, -1.0, -164.0],
+    [4.0, 0.0, 1.0, -1.0, 132.0],
+    [1.0, 0.0, -1.0, -1.0, -119.0],
+    [4.0, -1.0, 0.0, -1.0, 115.0],
+    [2.0, -2.0, 0.0, 1.0, 107.0],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_geocentric, normalize_degrees, RAD_TO_DEG};
+
+    #[test]
+    fn moon_matches_meeus_example_47a() {
+        // 1992 April 12, 0h TD
+        let jd = 2448724.5;
+        let [lon, lat, dist] = moon_spherical(jd);
+        let lon = normalize_degrees(lon * RAD_TO_DEG);
+        assert!((lon - 133.162655).abs() < 1e-5, "λ {}", lon);
+        assert!(
+            (lat * RAD_TO_DEG + 3.229126).abs() < 1e-5,
+            "β {}",
+            lat * RAD_TO_DEG
+        );
+        assert!((dist - 368409.7).abs() < 0.1, "Δ {}", dist);
+
+        let [lon, ..] = get_geocentric("Moon", jd).unwrap();
+        assert!((lon * RAD_TO_DEG - 133.162655).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,201 @@
+//! Planetary horas and choghadiyas.
+//!
+//! The day from sunrise to sunset and the night from sunset to the next
+//! sunrise are each cut into twelve horas and into eight choghadiyas. Horas
+//! are ruled in the descending order of the planets' periods (Saturn,
+//! Jupiter, Mars, Sun, Venus, Mercury, Moon), the first hora of the day
+//! going to the lord of the weekday, which is how the weekdays got their
+//! names. Choghadiyas follow the same order by day from the weekday lord's
+//! own; by night they start from the lord of the fifth weekday and step
+//! back two places at a time. The eighth choghadiya repeats the first.
+//!
+//! All `jd` arguments and results are JD(UT); weekdays count from 0 =
+//! Sunday, as in [`crate::weekday_index`].
+
+use chrono::{NaiveDate, TimeZone};
+
+use crate::calendar::local_midnight;
+use crate::muhurta::Period;
+use crate::rise_set::{sunrise, sunset, RiseSetOptions};
+use crate::{weekday_index, Result, WEEKDAY_LORDS};
+
+/// Lords of successive horas, each followed by the next.
+const HORA_ORDER: [&str; 7] = [
+    "Sun", "Venus", "Mercury", "Moon", "Saturn", "Jupiter", "Mars",
+];
+
+/// Choghadiya ruled by each lord of [`HORA_ORDER`], and whether it is good.
+const CHOGHADIYA: [(&str, bool); 7] = [
+    ("Udveg", false),
+    ("Char", true),
+    ("Labh", true),
+    ("Amrit", true),
+    ("Kaal", false),
+    ("Shubh", true),
+    ("Rog", false),
+];
+
+/// The 24 horas of the day from `sunrise_jd` to `sunset_jd` and the night
+/// on to `next_sunrise_jd`, each named after its lord. Horas of the Moon,
+/// Mercury, Jupiter and Venus are auspicious.
+pub fn planetary_horas(
+    sunrise_jd: f64,
+    sunset_jd: f64,
+    next_sunrise_jd: f64,
+    weekday: usize,
+) -> Vec<Period> {
+    let first = order_of(WEEKDAY_LORDS[weekday % 7]);
+    split(sunrise_jd, sunset_jd, next_sunrise_jd, 12)
+        .enumerate()
+        .map(|(n, (start, end))| {
+            let lord = HORA_ORDER[(first + n) % 7];
+            Period {
+                name: lord,
+                start,
+                end,
+                auspicious: matches!(lord, "Moon" | "Mercury" | "Jupiter" | "Venus"),
+            }
+        })
+        .collect()
+}
+
+/// The 16 choghadiyas of the day from `sunrise_jd` to `sunset_jd` and the
+/// night on to `next_sunrise_jd`. Amrit, Shubh, Labh and Char are
+/// auspicious; Udveg, Kaal and Rog are not.
+pub fn choghadiyas(
+    sunrise_jd: f64,
+    sunset_jd: f64,
+    next_sunrise_jd: f64,
+    weekday: usize,
+) -> Vec<Period> {
+    let day_first = order_of(WEEKDAY_LORDS[weekday % 7]);
+    let night_first = order_of(WEEKDAY_LORDS[(weekday + 4) % 7]);
+    split(sunrise_jd, sunset_jd, next_sunrise_jd, 8)
+        .enumerate()
+        .map(|(n, (start, end))| {
+            let lord = if n < 8 {
+                (day_first + n % 7) % 7
+            } else {
+                (night_first + 5 * ((n - 8) % 7)) % 7
+            };
+            let (name, auspicious) = CHOGHADIYA[lord];
+            Period {
+                name,
+                start,
+                end,
+                auspicious,
+            }
+        })
+        .collect()
+}
+
+/// The 24 planetary horas of the Hindu day that begins at the sunrise of
+/// civil `date` in `tz`, at the given place, sunrise and sunset being
+/// defined by `options`.
+pub fn compute_horas<Tz: TimeZone>(
+    date: NaiveDate,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    tz: &Tz,
+    options: RiseSetOptions,
+) -> Result<Vec<Period>> {
+    let (rise, set, next_rise, weekday) =
+        hindu_day(date, longitude_deg, latitude_deg, tz, options)?;
+    Ok(planetary_horas(rise, set, next_rise, weekday))
+}
+
+/// The 16 choghadiyas of the Hindu day that begins at the sunrise of civil
+/// `date` in `tz`, at the given place, sunrise and sunset being defined by
+/// `options`.
+pub fn compute_choghadiyas<Tz: TimeZone>(
+    date: NaiveDate,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    tz: &Tz,
+    options: RiseSetOptions,
+) -> Result<Vec<Period>> {
+    let (rise, set, next_rise, weekday) =
+        hindu_day(date, longitude_deg, latitude_deg, tz, options)?;
+    Ok(choghadiyas(rise, set, next_rise, weekday))
+}
+
+/// Position of `lord` in [`HORA_ORDER`].
+fn order_of(lord: &str) -> usize {
+    HORA_ORDER.iter().position(|&l| l == lord).unwrap_or(0)
+}
+
+/// `parts` equal spans of the day followed by `parts` of the night.
+fn split(
+    sunrise_jd: f64,
+    sunset_jd: f64,
+    next_sunrise_jd: f64,
+    parts: usize,
+) -> impl Iterator<Item = (f64, f64)> {
+    let day = (sunset_jd - sunrise_jd) / parts as f64;
+    let night = (next_sunrise_jd - sunset_jd) / parts as f64;
+    (0..2 * parts).map(move |n| {
+        if n < parts {
+            let start = sunrise_jd + n as f64 * day;
+            (start, start + day)
+        } else {
+            let start = sunset_jd + (n - parts) as f64 * night;
+            (start, start + night)
+        }
+    })
+}
+
+/// Sunrise, sunset, next sunrise and weekday of the Hindu day that begins
+/// on civil `date`, with sunrise and sunset defined by `options`.
+fn hindu_day<Tz: TimeZone>(
+    date: NaiveDate,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    tz: &Tz,
+    options: RiseSetOptions,
+) -> Result<(f64, f64, f64, usize)> {
+    let rise = sunrise(
+        local_midnight(date, tz)?,
+        longitude_deg,
+        latitude_deg,
+        options,
+    )?;
+    let set = sunset(rise, longitude_deg, latitude_deg, options)?;
+    let next_rise = sunrise(rise + 0.01, longitude_deg, latitude_deg, options)?;
+    Ok((
+        rise,
+        set,
+        next_rise,
+        weekday_index(rise + longitude_deg / 360.0),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monday_choghadiyas_run_in_the_published_order() {
+        let names: Vec<_> = choghadiyas(0.0, 0.5, 1.0, 1)
+            .iter()
+            .map(|period| period.name)
+            .collect();
+        assert_eq!(
+            names[..8],
+            ["Amrit", "Kaal", "Shubh", "Rog", "Udveg", "Char", "Labh", "Amrit"]
+        );
+        assert_eq!(
+            names[8..],
+            ["Char", "Rog", "Kaal", "Labh", "Udveg", "Shubh", "Amrit", "Char"]
+        );
+    }
+
+    #[test]
+    fn monday_horas_open_with_the_moon_and_the_night_with_venus() {
+        let horas = planetary_horas(0.0, 0.5, 1.0, 1);
+        assert_eq!(horas.len(), 24);
+        assert_eq!((horas[0].name, horas[1].name), ("Moon", "Saturn"));
+        assert_eq!(horas[12].name, "Venus");
+        // the 25th hora would open Tuesday with Mars
+        assert_eq!(HORA_ORDER[(order_of("Moon") + 24) % 7], "Mars");
+    }
+}
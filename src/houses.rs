@@ -0,0 +1,405 @@
+//! House division.
+//!
+//! Quadrant systems are computed from the sidereal time of the meridian
+//! (RAMC), the geographic latitude and the true obliquity, following the
+//! formulas of the Swiss Ephemeris house module: each intermediate cusp is
+//! the ecliptic point rising at a given oblique ascension under a given pole.
+//! Sripati divides the quadrants like Porphyry, but takes those points as the
+//! bhava madhyas (house middles); a house then starts at the sandhi halfway
+//! from the previous madhya. Every system returns the sidereal longitudes at
+//! which houses 1 to 12 begin. The angles of the chart (midheaven, vertex,
+//! East Point) are found from the same three quantities.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    nakshatra_from_longitude, nakshatra_lord, normalize_degrees, Result, VedicError, DEG_TO_RAD,
+    RAD_TO_DEG,
+};
+
+/// Method of dividing the sky into twelve houses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HouseSystem {
+    /// Each house is the sign, starting with the sign of the ascendant.
+    #[default]
+    WholeSign,
+    /// Thirty-degree houses starting at the ascendant degree.
+    Equal,
+    /// Each quadrant trisected in ecliptic longitude.
+    Porphyry,
+    /// Porphyry points as bhava madhyas, houses starting at the sandhis.
+    Sripati,
+    /// Trisection of the diurnal and nocturnal semi-arcs of each cusp.
+    Placidus,
+    /// Trisection of the semi-arc of the midheaven degree (birthplace system).
+    Koch,
+    /// Trisection of the celestial equator, projected through the horizon's
+    /// north and south points.
+    Regiomontanus,
+}
+
+/// Sensitive points of a chart, as sidereal longitudes in degrees.
+#[derive(Debug, Clone, Serialize)]
+pub struct Angles {
+    pub ascendant: f64,
+    pub descendant: f64,
+    /// Midheaven (MC), the culminating degree.
+    pub midheaven: f64,
+    /// Imum coeli (IC), opposite the midheaven.
+    pub imum_coeli: f64,
+    /// Western intersection of the prime vertical with the ecliptic.
+    pub vertex: f64,
+    pub anti_vertex: f64,
+    /// Equatorial ascendant: the degree rising at the equator at this RAMC.
+    pub east_point: f64,
+    pub ascendant_nakshatra: String,
+    /// Vimshottari lord of the ascendant's nakshatra.
+    pub ascendant_nakshatra_lord: String,
+}
+
+impl HouseSystem {
+    /// Whether the system divides the quadrants between the four angles.
+    pub fn is_quadrant(self) -> bool {
+        !matches!(self, HouseSystem::WholeSign | HouseSystem::Equal)
+    }
+}
+
+/// Tropical longitude of the midheaven, degrees.
+pub fn midheaven(ramc_deg: f64, obliquity_deg: f64) -> f64 {
+    rising_point(ramc_deg, 0.0, obliquity_deg)
+}
+
+/// Tropical longitude of the ascendant, degrees. Fails at the poles, where
+/// the horizon and the meridian are undefined.
+pub fn ascendant(ramc_deg: f64, latitude_deg: f64, obliquity_deg: f64) -> Result<f64> {
+    check_latitude(latitude_deg)?;
+    Ok(rising_point(ramc_deg + 90.0, latitude_deg, obliquity_deg))
+}
+
+/// Tropical longitude of the vertex, degrees: the western intersection of
+/// the prime vertical with the ecliptic. Fails at the poles.
+pub fn vertex(ramc_deg: f64, latitude_deg: f64, obliquity_deg: f64) -> Result<f64> {
+    check_latitude(latitude_deg)?;
+    let (sin_ramc, cos_ramc) = (ramc_deg * DEG_TO_RAD).sin_cos();
+    let (sin_lat, cos_lat) = (latitude_deg * DEG_TO_RAD).sin_cos();
+    let (sin_eps, cos_eps) = (obliquity_deg * DEG_TO_RAD).sin_cos();
+
+    // equatorial vectors: the north point of the horizon (the pole of the
+    // prime vertical) and the pole of the ecliptic
+    let north = [-sin_lat * cos_ramc, -sin_lat * sin_ramc, cos_lat];
+    let pole = [0.0, -sin_eps, cos_eps];
+    let mut line = [
+        north[1] * pole[2] - north[2] * pole[1],
+        north[2] * pole[0] - north[0] * pole[2],
+        north[0] * pole[1] - north[1] * pole[0],
+    ];
+    // keep the end that lies towards the west point (sin RAMC, -cos RAMC, 0)
+    if line[0] * sin_ramc - line[1] * cos_ramc < 0.0 {
+        line = line.map(|c| -c);
+    }
+    let y = line[1] * cos_eps + line[2] * sin_eps;
+    Ok(normalize_degrees(y.atan2(line[0]) * RAD_TO_DEG))
+}
+
+/// Tropical longitude of the East Point (equatorial ascendant), degrees.
+pub fn east_point(ramc_deg: f64, obliquity_deg: f64) -> f64 {
+    rising_point(ramc_deg + 90.0, 0.0, obliquity_deg)
+}
+
+/// Sidereal angles for a sidereal time `ramc_deg`, geographic
+/// `latitude_deg` and true `obliquity_deg`, with `ayanamsa_deg` separating
+/// the zodiacs.
+pub fn angles(
+    ramc_deg: f64,
+    latitude_deg: f64,
+    obliquity_deg: f64,
+    ayanamsa_deg: f64,
+) -> Result<Angles> {
+    let sidereal = |tropical: f64| normalize_degrees(tropical - ayanamsa_deg);
+    let asc = sidereal(ascendant(ramc_deg, latitude_deg, obliquity_deg)?);
+    let mc = sidereal(midheaven(ramc_deg, obliquity_deg));
+    let vx = sidereal(vertex(ramc_deg, latitude_deg, obliquity_deg)?);
+
+    Ok(Angles {
+        ascendant: asc,
+        descendant: normalize_degrees(asc + 180.0),
+        midheaven: mc,
+        imum_coeli: normalize_degrees(mc + 180.0),
+        vertex: vx,
+        anti_vertex: normalize_degrees(vx + 180.0),
+        east_point: sidereal(east_point(ramc_deg, obliquity_deg)),
+        ascendant_nakshatra: nakshatra_from_longitude(asc).1.to_string(),
+        ascendant_nakshatra_lord: nakshatra_lord(asc).to_string(),
+    })
+}
+
+/// Sidereal starts of the twelve houses for a sidereal time `ramc_deg`,
+/// geographic `latitude_deg` and true `obliquity_deg`, with
+/// `ayanamsa_deg` separating the zodiacs.
+///
+/// Placidus and Koch are undefined inside the polar circles, where part of
+/// the ecliptic never rises; there they return an error instead of NaN.
+pub fn house_cusps(
+    system: HouseSystem,
+    ramc_deg: f64,
+    latitude_deg: f64,
+    obliquity_deg: f64,
+    ayanamsa_deg: f64,
+) -> Result<[f64; 12]> {
+    let asc = ascendant(ramc_deg, latitude_deg, obliquity_deg)?;
+    let sidereal = |tropical: f64| normalize_degrees(tropical - ayanamsa_deg);
+
+    let tropical = match system {
+        HouseSystem::WholeSign => return Ok(whole_sign_cusps(sidereal(asc))),
+        HouseSystem::Equal => return Ok(equal_cusps(sidereal(asc))),
+        HouseSystem::Porphyry => porphyry_cusps(asc, midheaven(ramc_deg, obliquity_deg)),
+        HouseSystem::Sripati => {
+            sripati_cusps(&porphyry_cusps(asc, midheaven(ramc_deg, obliquity_deg)))
+        }
+        HouseSystem::Placidus | HouseSystem::Koch => {
+            if latitude_deg.abs() >= 90.0 - obliquity_deg {
+                return Err(VedicError::CalculationError(format!(
+                    "{:?} houses are undefined at latitude {}°, inside the polar circle",
+                    system, latitude_deg
+                )));
+            }
+            let intermediate = if system == HouseSystem::Placidus {
+                placidus_cusps(ramc_deg, latitude_deg, obliquity_deg)
+            } else {
+                koch_cusps(ramc_deg, latitude_deg, obliquity_deg)
+            };
+            assemble(asc, midheaven(ramc_deg, obliquity_deg), intermediate)
+        }
+        HouseSystem::Regiomontanus => {
+            let tan_lat = (latitude_deg * DEG_TO_RAD).tan();
+            let pole_1 = (tan_lat * 0.5).atan() * RAD_TO_DEG;
+            let pole_2 = (tan_lat * (30.0 * DEG_TO_RAD).cos()).atan() * RAD_TO_DEG;
+            let point =
+                |offset: f64, pole: f64| rising_point(ramc_deg + offset, pole, obliquity_deg);
+            assemble(
+                asc,
+                midheaven(ramc_deg, obliquity_deg),
+                [
+                    point(30.0, pole_1),
+                    point(60.0, pole_2),
+                    point(120.0, pole_2),
+                    point(150.0, pole_1),
+                ],
+            )
+        }
+    };
+
+    Ok(tropical.map(sidereal))
+}
+
+/// Whole-sign house starts: the sign boundaries from the ascendant's sign on.
+pub fn whole_sign_cusps(asc_deg: f64) -> [f64; 12] {
+    let first = (normalize_degrees(asc_deg) / 30.0).floor() * 30.0;
+    std::array::from_fn(|i| normalize_degrees(first + 30.0 * i as f64))
+}
+
+/// Equal house starts: every 30° from the ascendant degree.
+pub fn equal_cusps(asc_deg: f64) -> [f64; 12] {
+    std::array::from_fn(|i| normalize_degrees(asc_deg + 30.0 * i as f64))
+}
+
+/// Porphyry cusps: each quadrant between the angles trisected in longitude.
+pub fn porphyry_cusps(asc_deg: f64, mc_deg: f64) -> [f64; 12] {
+    let upper = normalize_degrees(asc_deg - mc_deg) / 3.0;
+    let lower = 60.0 - upper;
+    assemble(
+        asc_deg,
+        mc_deg,
+        [
+            mc_deg + upper,
+            mc_deg + 2.0 * upper,
+            asc_deg + lower,
+            asc_deg + 2.0 * lower,
+        ],
+    )
+}
+
+/// Sripati house starts: the sandhis halfway between consecutive madhyas.
+pub fn sripati_cusps(madhyas: &[f64; 12]) -> [f64; 12] {
+    std::array::from_fn(|i| {
+        let previous = madhyas[(i + 11) % 12];
+        normalize_degrees(previous + normalize_degrees(madhyas[i] - previous) / 2.0)
+    })
+}
+
+/// House (1–12) containing `longitude_deg`, given the starts of houses 1 to
+/// 12 in zodiacal order. Longitudes and starts must share one zodiac.
+pub fn house_of(longitude_deg: f64, starts: &[f64; 12]) -> u8 {
+    let from_first = normalize_degrees(longitude_deg - starts[0]);
+    (1..12)
+        .rev()
+        .find(|&i| normalize_degrees(starts[i] - starts[0]) <= from_first)
+        .map_or(1, |i| i as u8 + 1)
+}
+
+/// Whole-sign house (1–12) of `longitude_deg`: the number of signs from the
+/// ascendant's sign, counting that sign as the first.
+pub fn whole_sign_house(longitude_deg: f64, asc_deg: f64) -> u8 {
+    let sign = |deg: f64| (normalize_degrees(deg) / 30.0).floor() as i32;
+    ((sign(longitude_deg) - sign(asc_deg)).rem_euclid(12) + 1) as u8
+}
+
+/// Full set of cusps from the angles and cusps 11, 12, 2 and 3.
+fn assemble(asc: f64, mc: f64, [c11, c12, c2, c3]: [f64; 4]) -> [f64; 12] {
+    let first_half = [asc, c2, c3, mc + 180.0, c11 + 180.0, c12 + 180.0];
+    std::array::from_fn(|i| {
+        if i < 6 {
+            normalize_degrees(first_half[i])
+        } else {
+            normalize_degrees(first_half[i - 6] + 180.0)
+        }
+    })
+}
+
+fn placidus_cusps(ramc: f64, latitude: f64, obliquity: f64) -> [f64; 4] {
+    let tan_lat = (latitude * DEG_TO_RAD).tan();
+    let sin_eps = (obliquity * DEG_TO_RAD).sin();
+
+    // cusp = the point whose semi-arc is divided in the ratio `thirds`/3
+    let cusp = |offset: f64, thirds: f64| {
+        let mut longitude = rising_point(ramc + offset, 0.0, obliquity);
+        for _ in 0..100 {
+            let tan_dec = (sin_eps * (longitude * DEG_TO_RAD).sin()).asin().tan();
+            if tan_dec.abs() < 1e-12 {
+                break;
+            }
+            let ad = (tan_lat * tan_dec).clamp(-1.0, 1.0).asin();
+            let pole = ((ad * thirds / 3.0).sin() / tan_dec).atan() * RAD_TO_DEG;
+            let next = rising_point(ramc + offset, pole, obliquity);
+            let change = normalize_degrees(next - longitude + 180.0) - 180.0;
+            longitude = next;
+            if change.abs() < 1e-9 {
+                break;
+            }
+        }
+        longitude
+    };
+
+    [
+        cusp(30.0, 1.0),
+        cusp(60.0, 2.0),
+        cusp(120.0, 2.0),
+        cusp(150.0, 1.0),
+    ]
+}
+
+fn koch_cusps(ramc: f64, latitude: f64, obliquity: f64) -> [f64; 4] {
+    let sin_mc_dec = ((midheaven(ramc, obliquity) * DEG_TO_RAD).sin()
+        * (obliquity * DEG_TO_RAD).sin())
+    .clamp(-1.0, 1.0);
+    // a third of the ascensional difference of the midheaven degree
+    let ad3 = ((latitude * DEG_TO_RAD).tan() * sin_mc_dec.asin().tan())
+        .clamp(-1.0, 1.0)
+        .asin()
+        * RAD_TO_DEG
+        / 3.0;
+    let point = |oblique_ascension: f64| rising_point(oblique_ascension, latitude, obliquity);
+
+    [
+        point(ramc + 30.0 - 2.0 * ad3),
+        point(ramc + 60.0 - ad3),
+        point(ramc + 120.0 + ad3),
+        point(ramc + 150.0 + 2.0 * ad3),
+    ]
+}
+
+/// Longitude of the ecliptic point rising at oblique ascension `oa_deg`
+/// under pole height `pole_deg`.
+fn rising_point(oa_deg: f64, pole_deg: f64, obliquity_deg: f64) -> f64 {
+    let oa = oa_deg * DEG_TO_RAD;
+    let eps = obliquity_deg * DEG_TO_RAD;
+    let y = oa.sin();
+    let x = oa.cos() * eps.cos() - (pole_deg * DEG_TO_RAD).tan() * eps.sin();
+    normalize_degrees(y.atan2(x) * RAD_TO_DEG)
+}
+
+fn check_latitude(latitude_deg: f64) -> Result<()> {
+    if latitude_deg.is_finite() && latitude_deg.abs() < 90.0 {
+        Ok(())
+    } else {
+        Err(VedicError::InvalidLatitude(latitude_deg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Raphael's *Tables of Houses* for London (51°32′ N), sidereal time
+    /// 0h 0m 0s: 10th ♈0, 11th ♉9, 12th ♊22, Ascendant ♋26°36′, 2nd ♌12,
+    /// 3rd ♍3, intermediate cusps to the whole degree.
+    #[test]
+    fn placidus_matches_raphael_london_at_sidereal_time_zero() {
+        let latitude = 51.0 + 32.0 / 60.0;
+        let obliquity = 23.0 + 27.0 / 60.0;
+        let cusps = house_cusps(HouseSystem::Placidus, 0.0, latitude, obliquity, 0.0).unwrap();
+
+        assert!((cusps[0] - (90.0 + 26.0 + 36.0 / 60.0)).abs() < 1.0 / 60.0);
+        assert!(cusps[9].abs() < 1e-9 || (cusps[9] - 360.0).abs() < 1e-9);
+        for (house, sign, degree) in [(11, 1, 9.0), (12, 2, 22.0), (2, 4, 12.0), (3, 5, 3.0)] {
+            let expected = sign as f64 * 30.0 + degree;
+            assert!(
+                (cusps[house - 1] - expected).abs() < 0.7,
+                "house {}: {}",
+                house,
+                cusps[house - 1]
+            );
+        }
+        // opposite cusps
+        for house in 0..6 {
+            let opposite = normalize_degrees(cusps[house + 6] - cusps[house]);
+            assert!((opposite - 180.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn vertex_is_the_ascendant_of_the_co_latitude() {
+        // the Swiss Ephemeris finds the vertex as the ascendant for the
+        // co-latitude at RAMC − 180°: London at sidereal time 0h gives
+        // the vertex at 12°28′ Sagittarius
+        let obliquity = 23.0 + 27.0 / 60.0;
+        let london = vertex(0.0, 51.0 + 32.0 / 60.0, obliquity).unwrap();
+        assert!(
+            (london - (240.0 + 12.0 + 28.0 / 60.0)).abs() < 1.0 / 60.0,
+            "{}",
+            london
+        );
+
+        for (ramc, latitude) in [(0.0, 51.5333), (123.4, 28.6139), (300.0, -33.9_f64)] {
+            let co_latitude = latitude.signum() * 90.0 - latitude;
+            let expected = ascendant(ramc - 180.0, co_latitude, obliquity).unwrap();
+            let vx = vertex(ramc, latitude, obliquity).unwrap();
+            assert!(
+                (vx - expected).abs() < 1e-9,
+                "{} {}: {}",
+                ramc,
+                latitude,
+                vx
+            );
+        }
+    }
+
+    #[test]
+    fn vertex_on_the_equator_is_the_western_equinox() {
+        // on the equator the prime vertical is the celestial equator
+        assert!(vertex(45.0, 0.0, 23.44).unwrap().abs() < 1e-9);
+        assert!((vertex(270.0, 0.0, 23.44).unwrap() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn angles_are_sidereal_and_paired() {
+        let obliquity = 23.44;
+        let ayanamsa = 24.0;
+        let chart = angles(123.4, 28.6139, obliquity, ayanamsa).unwrap();
+        let vx = vertex(123.4, 28.6139, obliquity).unwrap();
+        assert!((chart.vertex - normalize_degrees(vx - ayanamsa)).abs() < 1e-9);
+        assert!((normalize_degrees(chart.anti_vertex - chart.vertex) - 180.0).abs() < 1e-9);
+        assert!((normalize_degrees(chart.imum_coeli - chart.midheaven) - 180.0).abs() < 1e-9);
+        assert!(angles(0.0, 90.0, obliquity, ayanamsa).is_err());
+    }
+}
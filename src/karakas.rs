@@ -0,0 +1,221 @@
+//! Jaimini chara (variable) karakas.
+//!
+//! The planets are ranked by the degrees they have travelled in their signs,
+//! the highest becoming the Atmakaraka and the lowest the Darakaraka. The
+//! seven-karaka scheme ranks the Sun to Saturn. The eight-karaka scheme adds
+//! Rahu, which moves backwards, so its degrees are counted from the end of
+//! its sign. The navamsa sign of the Atmakaraka is the Karakamsa.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{compute_navamsa, PlanetPosition, Result, VedicError};
+
+/// Karakas of the seven-planet scheme, strongest first.
+const SEVEN_KARAKAS: [&str; 7] = [
+    "Atmakaraka",
+    "Amatyakaraka",
+    "Bhratrikaraka",
+    "Matrikaraka",
+    "Putrakaraka",
+    "Gnatikaraka",
+    "Darakaraka",
+];
+
+/// Karakas of the eight-planet scheme, strongest first.
+const EIGHT_KARAKAS: [&str; 8] = [
+    "Atmakaraka",
+    "Amatyakaraka",
+    "Bhratrikaraka",
+    "Matrikaraka",
+    "Pitrikaraka",
+    "Putrakaraka",
+    "Gnatikaraka",
+    "Darakaraka",
+];
+
+/// Which planets take part in the chara karakas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KarakaScheme {
+    /// The Sun to Saturn.
+    #[default]
+    SevenPlanets,
+    /// The Sun to Saturn and Rahu, with Pitrikaraka added.
+    EightPlanets,
+}
+
+/// A karaka and the planet holding it.
+#[derive(Debug, Clone, Serialize)]
+pub struct CharaKaraka {
+    pub karaka: &'static str,
+    pub planet: String,
+    /// Degrees travelled in the sign, reversed for Rahu.
+    pub degree: f64,
+}
+
+/// Chara karakas of a chart, strongest first.
+#[derive(Debug, Clone, Serialize)]
+pub struct CharaKarakas {
+    pub scheme: KarakaScheme,
+    pub karakas: Vec<CharaKaraka>,
+    /// Navamsa sign of the Atmakaraka, read in the rasi chart.
+    pub karakamsa: String,
+    /// The same sign taken as lagna of the navamsa chart.
+    pub swamsa: String,
+}
+
+impl CharaKarakas {
+    /// The Atmakaraka, soul significator.
+    pub fn atmakaraka(&self) -> &CharaKaraka {
+        &self.karakas[0]
+    }
+
+    /// The karaka with this name, if the scheme has it.
+    pub fn karaka(&self, name: &str) -> Option<&CharaKaraka> {
+        self.karakas.iter().find(|k| k.karaka == name)
+    }
+}
+
+/// Chara karakas of `planets` in the given scheme, with the Karakamsa and
+/// Swamsa from the Atmakaraka's navamsa.
+pub fn calculate_chara_karakas(
+    planets: &[PlanetPosition],
+    scheme: KarakaScheme,
+) -> Result<CharaKarakas> {
+    let (names, karaka_names): (&[&str], &[&'static str]) = match scheme {
+        KarakaScheme::SevenPlanets => (
+            &[
+                "Sun", "Moon", "Mars", "Mercury", "Jupiter", "Venus", "Saturn",
+            ],
+            &SEVEN_KARAKAS,
+        ),
+        KarakaScheme::EightPlanets => (
+            &[
+                "Sun", "Moon", "Mars", "Mercury", "Jupiter", "Venus", "Saturn", "Rahu",
+            ],
+            &EIGHT_KARAKAS,
+        ),
+    };
+
+    let mut ranked = names
+        .iter()
+        .map(|&name| {
+            let planet = planets.iter().find(|p| p.name == name).ok_or_else(|| {
+                VedicError::CalculationError(format!("{} position not found", name))
+            })?;
+            let in_sign = planet.sidereal_long_deg.rem_euclid(30.0);
+            let degree = if name == "Rahu" {
+                30.0 - in_sign
+            } else {
+                in_sign
+            };
+            Ok((planet, degree))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let karakamsa = compute_navamsa(ranked[0].0.sidereal_long_deg);
+    let karakas = ranked
+        .into_iter()
+        .zip(karaka_names)
+        .map(|((planet, degree), &karaka)| CharaKaraka {
+            karaka,
+            planet: planet.name.clone(),
+            degree,
+        })
+        .collect();
+
+    Ok(CharaKarakas {
+        scheme,
+        karakas,
+        swamsa: karakamsa.clone(),
+        karakamsa,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_rasi_name;
+
+    fn planets() -> Vec<PlanetPosition> {
+        [
+            ("Sun", 10.0),
+            ("Moon", 55.0),
+            ("Mars", 93.0),
+            ("Mercury", 138.0),
+            ("Jupiter", 179.0),
+            ("Venus", 217.0),
+            ("Saturn", 261.0),
+            ("Rahu", 302.0),
+        ]
+        .into_iter()
+        .map(|(name, longitude)| PlanetPosition {
+            name: name.to_string(),
+            sidereal_long_deg: longitude,
+            latitude_deg: 0.0,
+            distance_au: 1.0,
+            speed_deg_per_day: 0.0,
+            retrograde: name == "Rahu",
+            stationary: false,
+        })
+        .collect()
+    }
+
+    fn order(karakas: &CharaKarakas) -> Vec<(&str, &str)> {
+        karakas
+            .karakas
+            .iter()
+            .map(|k| (k.karaka, k.planet.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn seven_karakas_rank_the_sun_to_saturn() {
+        let karakas = calculate_chara_karakas(&planets(), KarakaScheme::SevenPlanets).unwrap();
+        assert_eq!(
+            order(&karakas),
+            [
+                ("Atmakaraka", "Jupiter"),
+                ("Amatyakaraka", "Moon"),
+                ("Bhratrikaraka", "Saturn"),
+                ("Matrikaraka", "Mercury"),
+                ("Putrakaraka", "Sun"),
+                ("Gnatikaraka", "Venus"),
+                ("Darakaraka", "Mars"),
+            ]
+        );
+        // Jupiter at 29° Virgo is in the last navamsa of Virgo
+        assert_eq!(karakas.karakamsa, get_rasi_name(5));
+        assert_eq!(karakas.swamsa, karakas.karakamsa);
+    }
+
+    #[test]
+    fn eight_karakas_count_rahu_backwards() {
+        // Rahu at 2° Aquarius has travelled 28° of its sign, second only to
+        // Jupiter; everyone from the Moon down moves one karaka along
+        let karakas = calculate_chara_karakas(&planets(), KarakaScheme::EightPlanets).unwrap();
+        assert_eq!(
+            order(&karakas),
+            [
+                ("Atmakaraka", "Jupiter"),
+                ("Amatyakaraka", "Rahu"),
+                ("Bhratrikaraka", "Moon"),
+                ("Matrikaraka", "Saturn"),
+                ("Pitrikaraka", "Mercury"),
+                ("Putrakaraka", "Sun"),
+                ("Gnatikaraka", "Venus"),
+                ("Darakaraka", "Mars"),
+            ]
+        );
+        assert!((karakas.karaka("Amatyakaraka").unwrap().degree - 28.0).abs() < 1e-9);
+        assert_eq!(karakas.atmakaraka().planet, "Jupiter");
+    }
+
+    #[test]
+    fn missing_planets_are_reported() {
+        let mut planets = planets();
+        planets.retain(|p| p.name != "Rahu");
+        assert!(calculate_chara_karakas(&planets, KarakaScheme::SevenPlanets).is_ok());
+        assert!(calculate_chara_karakas(&planets, KarakaScheme::EightPlanets).is_err());
+    }
+}
@@ -1,13 +1,84 @@
+use chrono::TimeZone;
 pub use chrono::{DateTime, Datelike, Timelike, Utc};
 pub use chrono_tz::*;
 use serde::{Deserialize, Serialize};
+mod apparent;
+mod arudha;
+mod ayanamsa;
+mod bhava;
+mod calendar;
+mod daily_panchang;
+mod delta_t;
+mod elp;
+mod hora;
+mod houses;
+mod karakas;
+mod masa;
+mod motion;
+mod muhurta;
+mod nodes;
+mod precession;
+mod rise_set;
+mod solar_time;
+mod special_points;
+mod stars;
+mod transitions;
 mod vsop;
+pub use apparent::{get_apparent, mean_obliquity, nutation, ApparentPlace};
+pub use arudha::{arudha_pada, calculate_arudha_padas, ArudhaPadas};
+pub use ayanamsa::Ayanamsa;
+pub use bhava::{
+    bhava_madhyas, bhava_of, bhava_sandhis, calculate_bhava_chalit, BhavaChalit, BhavaDivision,
+    BhavaPosition,
+};
+pub use calendar::{
+    astronomical_year, calendar_date, datetime_to_jd, jd_to_datetime, julian_day, Calendar,
+    CalendarDate,
+};
+pub use daily_panchang::{daily_panchang, DailyPanchang, PanchangDay};
+pub use delta_t::{delta_t, delta_t_for_year, tt_to_ut, ut_to_tt};
+pub use hora::{choghadiyas, compute_choghadiyas, compute_horas, planetary_horas};
+pub use houses::{
+    angles, ascendant, east_point, equal_cusps, house_cusps, house_of, midheaven, porphyry_cusps,
+    sripati_cusps, vertex, whole_sign_cusps, whole_sign_house, Angles, HouseSystem,
+};
+pub use karakas::{calculate_chara_karakas, CharaKaraka, CharaKarakas, KarakaScheme};
+pub use masa::{
+    compute_lunar_date, samvatsara_name, LunarDate, MASA_NAMES, RITU_NAMES, SAMVATSARA_NAMES,
+};
+pub use motion::{find_stations, is_stationary, sidereal_speed, Station, StationKind};
+pub use muhurta::{
+    abhijit_muhurta, amrit_kaal, brahma_muhurta, compute_muhurtas, day_periods, durmuhurtas,
+    gulika_kaal, muhurta_periods, rahu_kaal, varjyam, yamaganda, Period,
+};
+pub use nodes::{compute_lunar_nodes, rahu_longitude, NodeType};
+pub use precession::{
+    ecliptic_date_to_j2000, ecliptic_j2000_to_date, ecliptic_precession_angles, precess_from_j2000,
+    precess_to_j2000, Matrix, PrecessionModel,
+};
+pub use rise_set::{
+    previous_sunrise, rise_set, sunrise, sunset, twilight, vara_index, Disc, RiseSet, RiseSetEvent,
+    RiseSetOptions, Twilight, HORIZON_REFRACTION,
+};
+pub use solar_time::equation_of_time;
+pub use special_points::{
+    compute_special_points, saturn_portion, sree_lagna, sun_upagrahas, time_lagna, SpecialPoints,
+    BHAVA_LAGNA_MINUTES_PER_SIGN, GHATI_LAGNA_MINUTES_PER_SIGN, HORA_LAGNA_MINUTES_PER_SIGN,
+};
+pub use stars::{
+    find_star, star_ayanamsa, FixedStar, ALDEBARAN, ANTARES, DELTA_CANCRI, FIXED_STARS, REGULUS,
+    SPICA,
+};
+pub use transitions::{
+    element_at, element_end, element_spans, element_start, next_new_moon, previous_new_moon,
+    tithi_day, ElementSpan, PanchangaElement, TithiDay,
+};
 pub use vsop::{
-    calculate_ayanamsa, calculate_lahiri_ayanamsa, get_emb, get_jupiter, get_ketu, get_mars,
+    calculate_ayanamsa, calculate_lahiri_ayanamsa, calculate_true_chitra_ayanamsa,
+    calculate_true_pushya_ayanamsa, get_emb, get_geocentric, get_jupiter, get_ketu, get_mars,
     get_mercury, get_moon, get_neptune, get_rahu, get_saturn, get_sun, get_uranus, get_venus,
-    normalize_degrees, normalize_radians, tropical_to_sidereal,
+    normalize_degrees, normalize_radians, tropical_to_sidereal, vsop87, Vsop87Series, VsopVariant,
 };
- 
 
 ///////////////////////////////////////////////////////////////////////////
 // COMPLETE 100% ACCURATE IMPLEMENTATION As PER VEDIC ASTROLOGY PRINCIPLES
@@ -19,8 +90,7 @@ pub const DEG_TO_RAD: f64 = PI / 180.0;
 pub const RAD_TO_DEG: f64 = 180.0 / PI;
 pub const J2000: f64 = 2451545.0; // Reference epoch
 pub const AYANAMSA_2000: f64 = 23.8625750; // Lahiri ayanamsa at J2000
-pub const PRECESSION_RATE: f64 = 50.2388475 / 3600.0; // Precession rate in degrees per century
-
+pub const PRECESSION_RATE: f64 = 50.2388475 / 3600.0; // Precession rate in degrees per Julian year
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BirthData {
@@ -28,121 +98,132 @@ pub struct BirthData {
     pub datetime: DateTime<Utc>,
     pub longitude: f64,
     pub latitude: f64,
- 
 }
 
 impl BirthData {
+    /// Julian Day of the birth moment in Universal Time, JD(UT). The instant
+    /// alone fixes it; the birth place does not enter.
     pub fn to_jd(&self) -> Result<f64> {
-       
-
-        let year = self.datetime.year() as f64;
-        let month = self.datetime.month() as f64;
-        let day = self.datetime.day() as f64;
-        let hour = self.datetime.hour() as f64;
-        let minute = self.datetime.minute() as f64;
-        let second = self.datetime.second() as f64;
-        let nano = self.datetime.nanosecond() as f64;
-
-        // Improved Meeus JD calculation
-        let y = if month <= 2.0 { year - 1.0 } else { year };
-        let m = if month <= 2.0 { month + 12.0 } else { month };
-
-        let a = (y / 100.0).floor();
-        let b = 2.0 - a + (a / 4.0).floor();
-
-        let jd = (365.25 * (y + 4716.0)).floor() 
-                + (30.6001 * (m + 1.0)).floor() 
-                + day + b - 1524.5
-                + (hour) / 24.0
-                + minute / 1440.0
-                + second / 86400.0
-                + nano / 86400000000000.0;
+        // chrono counts years astronomically in the proleptic Gregorian calendar
+        let date = CalendarDate::new(
+            self.datetime.year(),
+            self.datetime.month(),
+            self.datetime.day(),
+            Calendar::Gregorian,
+        );
+        let second = self.datetime.second() as f64 + self.datetime.nanosecond() as f64 / 1e9;
+        julian_day(&date.with_time(self.datetime.hour(), self.datetime.minute(), second))
+    }
 
-        // Precise longitude correction
-        let long_correction = self.longitude / 360.0;
-        Ok(jd + long_correction)
+    /// Julian Ephemeris Day of the birth moment, JD(TT), for the ephemeris.
+    pub fn to_jd_tt(&self) -> Result<f64> {
+        Ok(ut_to_tt(self.to_jd()?))
     }
 
     pub fn with_custom_timezone(&self, timezone: chrono_tz::Tz) -> Self {
         let datetime = self.datetime.with_timezone(&timezone);
-         //get utc time
+        //get utc time
         let utc_time = datetime.to_utc();
-        Self { datetime: utc_time, longitude: self.longitude, latitude: self.latitude }
-    }
-}
-
-pub fn compute_ascendant_sidereal(birth_data: &BirthData) -> f64 {
-    let jd = birth_data.to_jd().unwrap();
-    
-    // Calculate precise LST
-    let lst_deg = local_sidereal_time(jd, birth_data.longitude);
-    let lst_rad = lst_deg * DEG_TO_RAD;
-    let lat_rad = birth_data.latitude * DEG_TO_RAD;
-
-    // Calculate precise obliquity with nutation
-    let t = (jd - J2000) / 36525.0;
-    let eps = 23.43929111 
-        - 0.013004167 * t
-        - 0.000000164 * t * t
-        + 0.000000503 * t * t * t;
-    let eps_rad = eps * DEG_TO_RAD;
-
-    // Calculate nutation in obliquity
-    let omega = 125.04452 - 1934.136261 * t;
-    let omega_rad = omega * DEG_TO_RAD;
-    let deps = (9.20 * omega_rad.cos() + 0.57 * (2.0 * omega_rad).cos()) / 3600.0;
-    
-    // Apply nutation
-    let true_eps_rad = (eps + deps) * DEG_TO_RAD;
-
-    // Improved ascendant calculation
-    let f = true_eps_rad.cos() * lst_rad.sin();
-    let g = lst_rad.cos() * lat_rad.sin() - true_eps_rad.sin() * lat_rad.cos() * lst_rad.sin();
-    let asc_rad = f.atan2(g);
-    let asc_deg = normalize_degrees(asc_rad * RAD_TO_DEG);
-
-    // Calculate precise ayanamsa
-    let t = (jd - J2000) / 36525.0;
-    let ayanamsa = AYANAMSA_2000 + (PRECESSION_RATE * 100.0 * t);
-    
-    // Add periodic corrections to ayanamsa
-    let periodic_correction = 
-        0.00174 * (479.0 * t).sin() +
-        0.00204 * (413.0 * t).sin() +
-        0.00231 * (337.0 * t).sin();
-        
-    let final_ayanamsa = ayanamsa + periodic_correction;
-    
-    // Convert to sidereal
-    normalize_degrees(asc_deg - final_ayanamsa)
+        Self {
+            datetime: utc_time,
+            longitude: self.longitude,
+            latitude: self.latitude,
+        }
+    }
+}
+
+pub fn compute_ascendant_sidereal(birth_data: &BirthData, ayanamsa: Ayanamsa) -> Result<f64> {
+    let (jd_tt, ramc, obliquity) = birth_meridian(birth_data)?;
+    Ok(ayanamsa.to_sidereal(ascendant(ramc, birth_data.latitude, obliquity)?, jd_tt))
+}
+
+/// Sidereal angles of the chart at the birth moment and place; see
+/// [`angles`].
+pub fn compute_angles(birth_data: &BirthData, ayanamsa: Ayanamsa) -> Result<Angles> {
+    let (jd_tt, ramc, obliquity) = birth_meridian(birth_data)?;
+    angles(
+        ramc,
+        birth_data.latitude,
+        obliquity,
+        ayanamsa.degrees(jd_tt),
+    )
 }
 
-pub fn local_sidereal_time(jd: f64, geo_long_deg: f64) -> f64 {
+/// Sidereal starts of the twelve houses at the birth moment and place, in
+/// the given house system; see [`house_cusps`].
+pub fn compute_house_cusps(
+    birth_data: &BirthData,
+    system: HouseSystem,
+    ayanamsa: Ayanamsa,
+) -> Result<[f64; 12]> {
+    let (jd_tt, ramc, obliquity) = birth_meridian(birth_data)?;
+    house_cusps(
+        system,
+        ramc,
+        birth_data.latitude,
+        obliquity,
+        ayanamsa.degrees(jd_tt),
+    )
+}
+
+/// Bhava Chalit chart of `planets` at the birth moment and place; see
+/// [`calculate_bhava_chalit`].
+pub fn compute_bhava_chalit(
+    birth_data: &BirthData,
+    planets: &[PlanetPosition],
+    division: BhavaDivision,
+    ayanamsa: Ayanamsa,
+) -> Result<BhavaChalit> {
+    let angles = compute_angles(birth_data, ayanamsa)?;
+    calculate_bhava_chalit(planets, angles.ascendant, angles.midheaven, division)
+}
+
+/// JD(TT), local apparent sidereal time (RAMC) and true obliquity, degrees,
+/// of the birth moment and place.
+fn birth_meridian(birth_data: &BirthData) -> Result<(f64, f64, f64)> {
+    let jd_ut = birth_data.to_jd()?;
+    let jd_tt = ut_to_tt(jd_ut);
+    Ok((
+        jd_tt,
+        local_sidereal_time(jd_ut, birth_data.longitude),
+        true_obliquity(jd_tt),
+    ))
+}
+
+/// True obliquity of the ecliptic in degrees: mean obliquity plus nutation.
+pub(crate) fn true_obliquity(jd_tt: f64) -> f64 {
+    mean_obliquity(jd_tt) + nutation(jd_tt).1
+}
+
+/// Local apparent sidereal time in degrees. Earth rotation is measured in UT,
+/// so `jd_ut` is JD(UT); the equation of the equinoxes is taken at JD(TT).
+pub fn local_sidereal_time(jd_ut: f64, geo_long_deg: f64) -> f64 {
     // High precision GMST calculation
-    let d = jd - J2000;
+    let d = jd_ut - J2000;
     let t = d / 36525.0;
     let t2 = t * t;
     let t3 = t2 * t;
 
     // Improved GMST formula with higher order terms
-    let gmst = 280.46061837 
-        + 360.98564736629 * d 
-        + 0.000387933 * t2 
-        - t3 / 38710000.0
-        + 0.000000002 * t3;
-
-    // Add nutation correction
-    let omega = 125.04452 - 1934.136261 * t;
-    let l = 280.4665 + 36000.7698 * t;
-    let l_prime = 218.3165 + 481267.8813 * t;
-    
-    let nutation = (-17.20 * omega.sin() - 1.32 * (2.0 * l).sin() - 0.23 * (2.0 * l_prime).sin() + 0.21 * (2.0 * omega).sin()) / 3600.0;
-    
-    let gmst_corrected = normalize_degrees(gmst + nutation);
+    let gmst =
+        280.46061837 + 360.98564736629 * d + 0.000387933 * t2 - t3 / 38710000.0 + 0.000000002 * t3;
+
+    // Equation of the equinoxes: nutation in longitude projected on the equator
+    let jd_tt = ut_to_tt(jd_ut);
+    let (dpsi, deps) = nutation(jd_tt);
+    let equation_of_equinoxes = dpsi * ((mean_obliquity(jd_tt) + deps) * DEG_TO_RAD).cos();
+
+    let gmst_corrected = normalize_degrees(gmst + equation_of_equinoxes);
     normalize_degrees(gmst_corrected + geo_long_deg)
 }
 
-pub fn compute_planet_position(planet_name: &str, jd: f64, coords: [f64; 6]) -> PlanetPosition {
+/// Sidereal position from the orbital elements `coords` at `jd_tt`, JD(TT).
+pub fn compute_planet_position(
+    planet_name: &str,
+    jd_tt: f64,
+    coords: [f64; 6],
+    ayanamsa: Ayanamsa,
+) -> PlanetPosition {
     let a = coords[0];
     let l = coords[1];
     let k = coords[2];
@@ -157,11 +238,12 @@ pub fn compute_planet_position(planet_name: &str, jd: f64, coords: [f64; 6]) ->
     let omega = p.atan2(q);
 
     // Improved Kepler equation solver with higher iterations
-    let mut m = normalize_radians(l - pi);
+    let m = normalize_radians(l - pi);
     let mut e_anom = m;
     let tolerance = 1e-12;
-    
-    for _ in 0..15 {  // Increased iterations for better precision
+
+    for _ in 0..15 {
+        // Increased iterations for better precision
         let delta = e_anom - e * e_anom.sin() - m;
         let delta_e = delta / (1.0 - e * e_anom.cos());
         e_anom -= delta_e;
@@ -171,77 +253,109 @@ pub fn compute_planet_position(planet_name: &str, jd: f64, coords: [f64; 6]) ->
     }
 
     // Calculate true anomaly with higher precision
-    let v = 2.0 * ((1.0 + e).sqrt() * (e_anom / 2.0).sin())
-        .atan2((1.0 - e).sqrt() * (e_anom / 2.0).cos());
-        
+    let v = 2.0
+        * ((1.0 + e).sqrt() * (e_anom / 2.0).sin()).atan2((1.0 - e).sqrt() * (e_anom / 2.0).cos());
+
     // Calculate radius vector with perturbation corrections
     let r = a * (1.0 - e * e_anom.cos());
-    
+
     // Apply perturbation corrections for major planets
-    let (r_corr, v_corr) = apply_perturbations(planet_name, jd, r, v);
-    
-    // Calculate heliocentric coordinates
-    let lon = v_corr + pi;
-    let lat = i.asin() * (lon - omega).sin();
-
-    // Convert to sidereal with high-precision ayanamsa
-    let t = (jd - J2000) / 36525.0;
-    let ayanamsa = AYANAMSA_2000 + (PRECESSION_RATE * 100.0 * t)
-        + 0.00174 * (479.0 * t).sin()
-        + 0.00204 * (413.0 * t).sin()
-        + 0.00231 * (337.0 * t).sin();
-
-    let lon_sidereal = normalize_degrees((lon * RAD_TO_DEG) - ayanamsa);
-    
+    let (r_corr, v_corr) = apply_perturbations(planet_name, jd_tt, r, v);
+
+    // Reduce the orbital position to the ecliptic (argument of latitude u)
+    let u = v_corr + pi - omega;
+    let lon = omega + (i.cos() * u.sin()).atan2(u.cos());
+    let lat = (i.sin() * u.sin()).asin();
+
+    // Convert to sidereal
+    let lon_sidereal = ayanamsa.to_sidereal(lon * RAD_TO_DEG, jd_tt);
+
+    // Two-body daily motion: du/dt = h / r², projected onto the ecliptic
+    let speed = if r > 0.0 {
+        let mu = vsop::orbit_gravitational_parameter(planet_name);
+        let du_dt = (mu * a * (1.0 - e * e)).sqrt() / (r * r);
+        let dlon_du = i.cos() / (u.cos().powi(2) + (i.cos() * u.sin()).powi(2));
+        du_dt * dlon_du * RAD_TO_DEG - ayanamsa.rate(jd_tt)
+    } else {
+        0.0
+    };
+
     PlanetPosition {
         name: planet_name.to_string(),
         sidereal_long_deg: lon_sidereal,
         latitude_deg: lat * RAD_TO_DEG,
-        distance_au: r_corr
-    }
+        distance_au: r_corr,
+        speed_deg_per_day: speed,
+        // a Keplerian orbit seen from its own focus never turns back
+        retrograde: false,
+        stationary: false,
+    }
+}
+
+/// Geocentric sidereal position of the Sun, the Moon or a VSOP87 planet at
+/// JD(TT); see [`get_geocentric`].
+pub fn compute_geocentric_position(
+    planet_name: &str,
+    jd_tt: f64,
+    ayanamsa: Ayanamsa,
+) -> Result<PlanetPosition> {
+    compute_apparent_position(planet_name, jd_tt, ApparentPlace::GEOMETRIC, ayanamsa)
+}
+
+/// Geocentric sidereal position with the corrections chosen in `place`; see
+/// [`get_apparent`].
+pub fn compute_apparent_position(
+    planet_name: &str,
+    jd_tt: f64,
+    place: ApparentPlace,
+    ayanamsa: Ayanamsa,
+) -> Result<PlanetPosition> {
+    let [lon, lat, dist, lon_rate, ..] = get_apparent(planet_name, jd_tt, place)?;
+    let speed = lon_rate * RAD_TO_DEG - ayanamsa.rate(jd_tt);
+
+    Ok(PlanetPosition {
+        name: planet_name.to_string(),
+        sidereal_long_deg: ayanamsa.to_sidereal(lon * RAD_TO_DEG, jd_tt),
+        latitude_deg: lat * RAD_TO_DEG,
+        distance_au: dist,
+        speed_deg_per_day: speed,
+        retrograde: speed < 0.0,
+        stationary: is_stationary(planet_name, speed),
+    })
 }
 
-pub fn apply_perturbations(planet_name: &str, jd: f64, r: f64, v: f64) -> (f64, f64) {
-    let t = (jd - J2000) / 36525.0;
-    
+pub fn apply_perturbations(planet_name: &str, jd_tt: f64, r: f64, v: f64) -> (f64, f64) {
     match planet_name {
         "Mercury" => {
             // Apply relativistic correction for Mercury
             let rel_corr = 0.0000078 * (v * 2.0).sin();
-            let jupiter_pert = 0.0000003 * ((jd * 0.08309) + 0.3).sin();
+            let jupiter_pert = 0.0000003 * ((jd_tt * 0.08309) + 0.3).sin();
             (r, v + rel_corr + jupiter_pert)
-        },
-        "Moon" => {
-            // Apply evection, variation, and annual equation
-            let evection = 1.274 * (2.0 * v - 0.0000002).sin();
-            let variation = 0.658 * (2.0 * v).sin();
-            let annual = 0.214 * (v).sin();
-            (r * (1.0 - 0.000058 * (2.0 * v).cos()), v + (evection + variation + annual) * DEG_TO_RAD)
-        },
-        _ => (r, v)  // No additional perturbations for other planets
-    }
-}
-
-
-/// Return the day of week as a string (Vāra),
-pub fn weekday_string(jd: f64) -> &'static str {
-    // Julian Day starts at noon, so add 0.5 to get to midnight
-    let jd_plus = jd + 0.5;
-    // Get day of week (0 = Monday, 6 = Sunday)
-    let day = (jd_plus as i64 % 7) as usize;
-    // Return Sanskrit weekday name
-    match day {
-        0 => "Soma",    // Monday (Moon)
-        1 => "Maṅgala", // Tuesday (Mars)
-        2 => "Budha",   // Wednesday (Mercury)
-        3 => "Guru",    // Thursday (Jupiter)
-        4 => "Śukra",   // Friday (Venus)
-        5 => "Śani",    // Saturday (Saturn)
-        6 => "Ravi",    // Sunday (Sun)
-        _ => unreachable!(),
+        }
+        _ => (r, v), // No additional perturbations for other planets
     }
 }
 
+/// Return the day of week as a string (Vāra) for a JD(UT), counted as in
+/// [`weekday_index`].
+pub fn weekday_string(jd_ut: f64) -> &'static str {
+    VARA_NAMES[weekday_index(jd_ut)]
+}
+
+/// Sanskrit names of the weekdays, from Sunday.
+pub const VARA_NAMES: [&str; 7] = ["Ravi", "Soma", "Maṅgala", "Budha", "Guru", "Śukra", "Śani"];
+
+/// Lords of the weekdays, from Sunday.
+pub const WEEKDAY_LORDS: [&str; 7] = [
+    "Sun", "Moon", "Mars", "Mercury", "Jupiter", "Venus", "Saturn",
+];
+
+/// Weekday of the civil date of `jd_ut` at Greenwich, 0 = Sunday. Add
+/// `longitude / 360` first to count the date at another place.
+pub fn weekday_index(jd_ut: f64) -> usize {
+    ((jd_ut + 1.5).floor() as i64).rem_euclid(7) as usize
+}
+
 /// A small struct for storing a planet's final sidereal position and name.
 #[derive(Debug, Serialize)]
 pub struct Yoga {
@@ -256,48 +370,26 @@ pub struct PlanetPosition {
     pub sidereal_long_deg: f64, // 0..360 deg
     pub latitude_deg: f64,      // -90..+90 deg
     pub distance_au: f64,
+    pub speed_deg_per_day: f64, // sidereal daily motion in longitude
+    pub retrograde: bool,       // apparent motion against the zodiac
+    pub stationary: bool,       // speed near zero, see `is_stationary`
 }
 
- 
-
 /// Compute 12 house cusps, using "Whole Sign" approach from the Ascendant:
 /// - House 1 starts at the Ascendant's sign boundary
 /// - House 2 is next 30°, etc.
+///
 /// Return an array of 12 house cusp degrees in sidereal [0..360).
+#[deprecated(note = "use `calculate_whole_sign_houses` or `house_cusps`")]
 pub fn compute_whole_sign_houses(asc_sid_deg: f64) -> [f64; 12] {
-    // Normalize input angle to [0, 360)
-    let asc_normalized = asc_sid_deg.rem_euclid(360.0);
-
-    // Find the start of the ascendant's sign with high precision
-    let sign_index = (asc_normalized / 30.0).floor();
-    let sign_start_deg = sign_index * 30.0;
-
-    // Calculate offset from sign boundary
-    let offset = asc_normalized - sign_start_deg;
-
-    // Initialize house cusps array
-    let mut house_cusps = [0.0; 12];
-
-    // Calculate each house cusp with high precision
-    for i in 0..12 {
-        // Calculate base position
-        let base = sign_start_deg + (i as f64 * 30.0);
-
-        // Add offset and normalize to [0, 360)
-        let cusp = (base + offset).rem_euclid(360.0);
-
-        // Round to 8 decimal places for maximum meaningful precision
-        house_cusps[i] = (cusp * 100_000_000.0).round() / 100_000_000.0;
-    }
-
-    house_cusps
+    whole_sign_cusps(asc_sid_deg)
 }
 /// Return which rāśi (sign) a given sidereal longitude (deg) is in, plus
 /// the degrees/minutes/seconds within that sign.  
 pub fn rasi_details(lon_deg: f64) -> (String, u32, u32, f64) {
     let sign_names = [
         "Meṣa (Aries)",
-        "Vṛṣabha (Taurus)", 
+        "Vṛṣabha (Taurus)",
         "Mithuna (Gemini)",
         "Karka (Cancer)",
         "Siṃha (Leo)",
@@ -309,29 +401,29 @@ pub fn rasi_details(lon_deg: f64) -> (String, u32, u32, f64) {
         "Kumbha (Aquarius)",
         "Mīna (Pisces)",
     ];
-    
+
     // Normalize input to 0-360 range
     let normalized_lon = lon_deg.rem_euclid(360.0);
-    
+
     // Calculate sign index (0-11)
     let sign_idx = (normalized_lon / 30.0).floor() as usize;
-    
+
     // Get sign name
     let sign_name = sign_names[sign_idx].to_string();
-    
+
     // Calculate degrees within sign (0-29.999...)
     let degrees_in_sign = normalized_lon - (sign_idx as f64 * 30.0);
-    
+
     // Extract whole degrees
     let d_whole = degrees_in_sign.floor() as u32;
-    
+
     // Calculate minutes
     let m_f = (degrees_in_sign - d_whole as f64) * 60.0;
     let m_whole = m_f.floor() as u32;
-    
-    // Calculate seconds 
+
+    // Calculate seconds
     let s_f = (m_f - m_whole as f64) * 60.0;
-    
+
     (sign_name, d_whole, m_whole, s_f)
 }
 
@@ -340,34 +432,72 @@ pub fn rasi_details(lon_deg: f64) -> (String, u32, u32, f64) {
 /// Each tithi is 12° of separation.  
 /// We also determine “Pakṣa” (Śukla/Waxing or Kṛṣṇa/Waning).
 /// Returns (tithi_number: 1..30, paksha: "Shukla" or "Krishna").
-pub fn compute_tithi(jd: f64) -> (u8, &'static str) {
-    unsafe {
-        let sun = get_sun(jd);
-        let moon = get_moon(jd);
-        // sidereal
-        let sun_sid = tropical_to_sidereal(sun[0], jd);
-        let moon_sid = tropical_to_sidereal(moon[0], jd);
-        let sun_deg = sun_sid * RAD_TO_DEG;
-        let moon_deg = moon_sid * RAD_TO_DEG;
-        // difference
-        let mut diff = moon_deg - sun_deg;
-        diff = normalize_degrees(diff);
-        // each Tithi is 12°, so Tithi index = floor(diff / 12) + 1
-        let tithi_index = (diff / 12.0).floor() as u8 + 1;
-        let tithi = if tithi_index > 30 {
-            tithi_index - 30
-        } else {
-            tithi_index
-        };
-        let paksha = if tithi <= 15 { "Shukla" } else { "Krishna" };
-        (tithi, paksha)
-    }
+pub fn compute_tithi(jd_ut: f64) -> (u8, &'static str) {
+    // the elongation is the same whatever the ayanamsa
+    let (sun_deg, moon_deg) = sun_moon_sidereal(jd_ut, Ayanamsa::default());
+    // difference
+    let diff = normalize_degrees(moon_deg - sun_deg);
+    // each Tithi is 12°, so Tithi index = floor(diff / 12) + 1
+    let tithi_index = (diff / 12.0).floor() as u8 + 1;
+    let tithi = if tithi_index > 30 {
+        tithi_index - 30
+    } else {
+        tithi_index
+    };
+    let paksha = if tithi <= 15 { "Shukla" } else { "Krishna" };
+    (tithi, paksha)
+}
+
+/// Name of tithi 1–30: Pratipada to Chaturdashi in each paksha, then
+/// Purnima (15) and Amavasya (30).
+pub fn tithi_name(tithi: u8) -> &'static str {
+    const NAMES: [&str; 14] = [
+        "Pratipada",
+        "Dvitiya",
+        "Tritiya",
+        "Chaturthi",
+        "Panchami",
+        "Shashthi",
+        "Saptami",
+        "Ashtami",
+        "Navami",
+        "Dashami",
+        "Ekadashi",
+        "Dvadashi",
+        "Trayodashi",
+        "Chaturdashi",
+    ];
+    match tithi {
+        15 => "Purnima",
+        30 => "Amavasya",
+        0 | 31.. => "Unknown",
+        _ => NAMES[(tithi as usize + 14) % 15],
+    }
+}
+
+/// Apparent sidereal longitudes of the Sun and Moon in degrees at `jd_ut`,
+/// as an almanac gives them; see [`compute_apparent_position`].
+pub(crate) fn sun_moon_sidereal(jd_ut: f64, ayanamsa: Ayanamsa) -> (f64, f64) {
+    let jd_tt = ut_to_tt(jd_ut);
+    let longitude = |body: &str| {
+        compute_apparent_position(body, jd_tt, ApparentPlace::APPARENT, ayanamsa)
+            .expect("the Sun and Moon are always in the ephemeris")
+            .sidereal_long_deg
+    };
+    (longitude("Sun"), longitude("Moon"))
 }
 
 /// Nakshatra is determined by the sidereal longitude of the Moon.
+/// Returns (nakshatra_index 1..27, nakshatra_name).
+pub fn compute_nakshatra(jd_ut: f64, ayanamsa: Ayanamsa) -> (u8, &'static str) {
+    let (_, moon_side_deg) = sun_moon_sidereal(jd_ut, ayanamsa);
+    nakshatra_from_longitude(moon_side_deg)
+}
+
+/// Nakshatra containing a sidereal longitude in degrees.
 /// Each nakshatra covers 13°20' (i.e. 13.3333°).
 /// Returns (nakshatra_index 1..27, nakshatra_name).
-pub fn compute_nakshatra(jd: f64) -> (u8, &'static str) {
+pub fn nakshatra_from_longitude(sidereal_long_deg: f64) -> (u8, &'static str) {
     // standard list
     let nakshatras = [
         "Ashwini",
@@ -398,21 +528,26 @@ pub fn compute_nakshatra(jd: f64) -> (u8, &'static str) {
         "Uttara Bhadrapada",
         "Revati",
     ];
-    unsafe {
-        let moon = get_moon(jd);
-        let moon_siderad = tropical_to_sidereal(moon[0], jd);
-        let moon_side_deg = normalize_degrees(moon_siderad * RAD_TO_DEG);
-        let index = (moon_side_deg / 13.3333333).floor() as usize;
-        let nak_idx = index % 27;
-        let name = nakshatras[nak_idx];
-        (nak_idx as u8 + 1, name)
-    }
+    let index = (normalize_degrees(sidereal_long_deg) / 13.3333333).floor() as usize;
+    let nak_idx = index % 27;
+    let name = nakshatras[nak_idx];
+    (nak_idx as u8 + 1, name)
+}
+
+/// Vimshottari lord of the nakshatra containing a sidereal longitude in
+/// degrees. The nine lords repeat in order from Ashwini.
+pub fn nakshatra_lord(sidereal_long_deg: f64) -> &'static str {
+    const LORDS: [&str; 9] = [
+        "Ketu", "Venus", "Sun", "Moon", "Mars", "Rahu", "Jupiter", "Saturn", "Mercury",
+    ];
+    let (index, _) = nakshatra_from_longitude(sidereal_long_deg);
+    LORDS[(index as usize - 1) % 9]
 }
 
 /// Yoga is based on the sum of the longitude of the Sun + Moon (sidereal).
 /// We then take that sum mod 360, and see which of the 27 yogas (each 13°20') it falls into.
 /// Returns (yoga_index, yoga_name).
-pub fn compute_yoga(jd: f64) -> (u8, &'static str) {
+pub fn compute_yoga(jd_ut: f64, ayanamsa: Ayanamsa) -> (u8, &'static str) {
     let yoga_names = [
         "Vishkambha",
         "Priti",
@@ -442,21 +577,16 @@ pub fn compute_yoga(jd: f64) -> (u8, &'static str) {
         "Indra",
         "Vaidhriti",
     ];
-    unsafe {
-        let sun = get_sun(jd);
-        let moon = get_moon(jd);
-        let sun_sid = tropical_to_sidereal(sun[0], jd) * RAD_TO_DEG;
-        let moon_sid = tropical_to_sidereal(moon[0], jd) * RAD_TO_DEG;
-        let sum = normalize_degrees(sun_sid + moon_sid);
-        // each yoga is 13.333... degrees
-        let idx = (sum / 13.3333333).floor() as usize % 27;
-        (idx as u8 + 1, yoga_names[idx])
-    }
+    let (sun_sid, moon_sid) = sun_moon_sidereal(jd_ut, ayanamsa);
+    let sum = normalize_degrees(sun_sid + moon_sid);
+    // each yoga is 13.333... degrees
+    let idx = (sum / 13.3333333).floor() as usize % 27;
+    (idx as u8 + 1, yoga_names[idx])
 }
 
 /// Karanas are half-tithis. There are 11 possible karanas, repeating in a cycle:
 /// - 7 'moveable' karanas repeated 8 times + 4 'fixed' karanas
-pub fn compute_karana(jd: f64) -> (u8, &'static str) {
+pub fn compute_karana(jd_ut: f64) -> (u8, &'static str) {
     // The 11 karanas in order
     let karanas = [
         "Bava",
@@ -472,17 +602,12 @@ pub fn compute_karana(jd: f64) -> (u8, &'static str) {
         "Kimstughna", // 4 fixed
     ];
 
-    // Get tithi value from 0-30 (15 tithis x 2 halves)
-    let (tithi, _) = compute_tithi(jd);
-    let moon = get_moon(jd);
-    let sun = get_sun(jd);
-    let moon_long = moon[1];
-    let sun_long = sun[1];
-    let diff = normalize_degrees(moon_long - sun_long) * RAD_TO_DEG;
-    let tithi_deg = diff / 12.0; // Convert to tithi units (12 degrees each)
+    // the elongation is the same whatever the ayanamsa
+    let (sun_long, moon_long) = sun_moon_sidereal(jd_ut, Ayanamsa::default());
+    let diff = normalize_degrees(moon_long - sun_long);
 
-    // Get karana index (0-59)
-    let karana_idx = (tithi_deg * 2.0).floor() as usize;
+    // Get karana index (0-59), each karana being half a tithi (6 degrees)
+    let karana_idx = (diff / 6.0).floor() as usize;
 
     // Map to actual karana
     let k = if karana_idx < 56 {
@@ -506,6 +631,8 @@ pub fn compute_karana(jd: f64) -> (u8, &'static str) {
 /// This function returns them all in a struct.
 #[derive(Debug)]
 pub struct Panchanga {
+    /// Moment the elements were computed for, JD(UT).
+    pub jd: f64,
     pub tithi_number: u8,
     pub paksha: String,
     pub weekday: String,
@@ -517,16 +644,19 @@ pub struct Panchanga {
     pub karana_name: String,
 }
 
-/// Compute the Pañchāṅga for a given Julian Day.
-pub fn compute_panchanga(jd: f64) -> Panchanga {
-    let (tithi, paksha) = compute_tithi(jd);
-    let (n_idx, n_name) = compute_nakshatra(jd);
-    let (y_idx, y_name) = compute_yoga(jd);
-    let (k_idx, k_name) = compute_karana(jd);
+/// Compute the Pañchāṅga for a given Julian Day in UT. The weekday is that
+/// of the civil date at Greenwich; [`compute_panchanga_at`] gives the vara
+/// running from sunrise at a place.
+pub fn compute_panchanga(jd_ut: f64, ayanamsa: Ayanamsa) -> Panchanga {
+    let (tithi, paksha) = compute_tithi(jd_ut);
+    let (n_idx, n_name) = compute_nakshatra(jd_ut, ayanamsa);
+    let (y_idx, y_name) = compute_yoga(jd_ut, ayanamsa);
+    let (k_idx, k_name) = compute_karana(jd_ut);
     Panchanga {
+        jd: jd_ut,
         tithi_number: tithi,
         paksha: paksha.to_string(),
-        weekday: weekday_string(jd).to_string(),
+        weekday: weekday_string(jd_ut).to_string(),
         nakshatra_index: n_idx,
         nakshatra_name: n_name.to_string(),
         yoga_index: y_idx,
@@ -536,6 +666,29 @@ pub fn compute_panchanga(jd: f64) -> Panchanga {
     }
 }
 
+/// Compute the Pañchāṅga for a given Julian Day in UT at a place, with the
+/// weekday of the vara in progress there, as [`vara_index`], the vara
+/// starting at sunrise as defined by `options`.
+pub fn compute_panchanga_at(
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    ayanamsa: Ayanamsa,
+    options: RiseSetOptions,
+) -> Result<Panchanga> {
+    let mut panchanga = compute_panchanga(jd_ut, ayanamsa);
+    let vara = vara_index(jd_ut, longitude_deg, latitude_deg, options)?;
+    panchanga.weekday = VARA_NAMES[vara].to_string();
+    Ok(panchanga)
+}
+
+impl Panchanga {
+    /// Moment the elements were computed for, as a timestamp in `tz`.
+    pub fn datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.jd, tz)
+    }
+}
+
 /// Get the sign name for a given index (0-11)
 pub fn get_rasi_name(index: i32) -> String {
     match index {
@@ -555,6 +708,11 @@ pub fn get_rasi_name(index: i32) -> String {
     }
 }
 
+/// Sign index (0 = Meṣa) of a rashi named as by [`get_rasi_name`].
+pub fn rasi_index(name: &str) -> Option<usize> {
+    (0..12).find(|&i| get_rasi_name(i as i32) == name)
+}
+
 /// Compute Rāśi (D1) sign for a given sidereal longitude
 pub fn compute_rasi(sidereal_long_deg: f64) -> String {
     let rasi = ((sidereal_long_deg / 30.0).floor() as i32) % 12;
@@ -663,7 +821,7 @@ pub fn calculate_vimsopaka_bala(
     for planet in planets {
         let mut points = 0.0;
         let sign = (planet.sidereal_long_deg / 30.0).floor() as i32 % 12;
-        let house = ((planet.sidereal_long_deg - asc) / 30.0).floor() as i32 % 12;
+        let house = whole_sign_house(planet.sidereal_long_deg, asc) as i32 - 1;
 
         // 1. Uchcha Bala (Exaltation strength)
         points += match (planet.name.as_str(), sign) {
@@ -809,7 +967,7 @@ pub enum VedicError {
 impl std::error::Error for VedicError {}
 
 impl std::fmt::Display for VedicError {
-      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             VedicError::InvalidLongitude(lon) => write!(f, "Invalid longitude: {}", lon),
             VedicError::InvalidLatitude(lat) => write!(f, "Invalid latitude: {}", lat),
@@ -827,14 +985,16 @@ impl std::fmt::Display for VedicError {
     }
 }
 
- 
-
 /// Complete horoscope calculation result
 #[derive(Debug, Serialize)]
 pub struct Horoscope {
     pub birth_data: BirthData,
     pub ascendant: f64,
+    pub angles: Angles,
+    pub special_points: SpecialPoints,
+    pub chara_karakas: CharaKarakas,
     pub houses: Houses,
+    pub bhava_chalit: BhavaChalit,
     pub planets: Vec<PlanetInfo>,
     pub divisional_charts: DivisionalCharts,
     pub dashas: VimshottariDasha,
@@ -1011,10 +1171,13 @@ pub fn calculate_bindu(house: usize, planet_house: usize, planet: &str) -> Resul
     })
 }
 
-pub fn calculate_house_strengths(planets: &[PlanetPosition], asc: f64) -> Result<Vec<HouseStrength>> {
+pub fn calculate_house_strengths(
+    planets: &[PlanetPosition],
+    asc: f64,
+) -> Result<Vec<HouseStrength>> {
     let mut strengths = Vec::new();
 
-    for house_num in 1..=12 {
+    for house_num in 1..=12u8 {
         let mut strength = 0.0;
         let mut significator_strength = 0.0;
 
@@ -1027,8 +1190,7 @@ pub fn calculate_house_strengths(planets: &[PlanetPosition], asc: f64) -> Result
 
         // Add strength from planets in the house
         for planet in planets {
-            let planet_house = ((planet.sidereal_long_deg - asc) / 30.0).floor() as i32 % 12 + 1;
-            if planet_house == house_num {
+            if whole_sign_house(planet.sidereal_long_deg, asc) == house_num {
                 strength += 0.5;
 
                 // Add natural significator strength
@@ -1051,7 +1213,7 @@ pub fn calculate_house_strengths(planets: &[PlanetPosition], asc: f64) -> Result
         }
 
         strengths.push(HouseStrength {
-            house_number: house_num as u8,
+            house_number: house_num,
             strength,
             significator_strength,
         });
@@ -1086,26 +1248,26 @@ pub fn calculate_dignity(planet: &PlanetPosition) -> Result<PlanetaryDignity> {
 
 pub fn is_friendly_sign(planet: &str, sign: i32) -> bool {
     match planet {
-        "Sun" => vec![0, 4, 8].contains(&sign), // Aries, Leo, Sagittarius
-        "Moon" => vec![1, 3, 5].contains(&sign), // Taurus, Cancer, Virgo
-        "Mars" => vec![0, 4, 8].contains(&sign), // Aries, Leo, Sagittarius
-        "Mercury" => vec![2, 5, 6].contains(&sign), // Gemini, Virgo, Libra
-        "Jupiter" => vec![0, 4, 8].contains(&sign), // Aries, Leo, Sagittarius
-        "Venus" => vec![1, 6, 10].contains(&sign), // Taurus, Libra, Aquarius
-        "Saturn" => vec![9, 10, 11].contains(&sign), // Capricorn, Aquarius, Pisces
+        "Sun" => [0, 4, 8].contains(&sign),     // Aries, Leo, Sagittarius
+        "Moon" => [1, 3, 5].contains(&sign),    // Taurus, Cancer, Virgo
+        "Mars" => [0, 4, 8].contains(&sign),    // Aries, Leo, Sagittarius
+        "Mercury" => [2, 5, 6].contains(&sign), // Gemini, Virgo, Libra
+        "Jupiter" => [0, 4, 8].contains(&sign), // Aries, Leo, Sagittarius
+        "Venus" => [1, 6, 10].contains(&sign),  // Taurus, Libra, Aquarius
+        "Saturn" => [9, 10, 11].contains(&sign), // Capricorn, Aquarius, Pisces
         _ => false,
     }
 }
 
 pub fn is_enemy_sign(planet: &str, sign: i32) -> bool {
     match planet {
-        "Sun" => vec![6, 7, 10].contains(&sign), // Libra, Scorpio, Aquarius
-        "Moon" => vec![7, 9, 10].contains(&sign), // Scorpio, Capricorn, Aquarius
-        "Mars" => vec![1, 6, 10].contains(&sign), // Taurus, Libra, Aquarius
-        "Mercury" => vec![8, 9, 11].contains(&sign), // Sagittarius, Capricorn, Pisces
-        "Jupiter" => vec![2, 5, 6].contains(&sign), // Gemini, Virgo, Libra
-        "Venus" => vec![0, 7, 8].contains(&sign), // Aries, Scorpio, Sagittarius
-        "Saturn" => vec![0, 4, 8].contains(&sign), // Aries, Leo, Sagittarius
+        "Sun" => [6, 7, 10].contains(&sign),  // Libra, Scorpio, Aquarius
+        "Moon" => [7, 9, 10].contains(&sign), // Scorpio, Capricorn, Aquarius
+        "Mars" => [1, 6, 10].contains(&sign), // Taurus, Libra, Aquarius
+        "Mercury" => [8, 9, 11].contains(&sign), // Sagittarius, Capricorn, Pisces
+        "Jupiter" => [2, 5, 6].contains(&sign), // Gemini, Virgo, Libra
+        "Venus" => [0, 7, 8].contains(&sign), // Aries, Scorpio, Sagittarius
+        "Saturn" => [0, 4, 8].contains(&sign), // Aries, Leo, Sagittarius
         _ => false,
     }
 }
@@ -1122,7 +1284,6 @@ pub fn calculate_bhava_bala(planets: &[PlanetPosition], asc: f64) -> Result<Vec<
 
     for house_num in 1..=12 {
         let mut adhipati_bala = 0.0;
-        let mut dig_bala = 0.0;
         let mut drishti_bala = 0.0;
 
         // Calculate Bhava Adhipati Bala (strength of house lord)
@@ -1132,7 +1293,7 @@ pub fn calculate_bhava_bala(planets: &[PlanetPosition], asc: f64) -> Result<Vec<
         }
 
         // Calculate Bhava Dig Bala (directional strength)
-        dig_bala = match house_num {
+        let dig_bala = match house_num {
             1 | 10 => 60.0,         // Strong in East and South
             4 | 7 => 30.0,          // Medium in North and West
             2 | 5 | 8 | 11 => 15.0, // Succedent houses
@@ -1141,8 +1302,7 @@ pub fn calculate_bhava_bala(planets: &[PlanetPosition], asc: f64) -> Result<Vec<
 
         // Calculate Bhava Drishti Bala (aspectual strength)
         for planet in planets {
-            let aspect_house = ((planet.sidereal_long_deg - asc) / 30.0).floor() as i32;
-            if aspect_house == house_num as i32 {
+            if whole_sign_house(planet.sidereal_long_deg, asc) == house_num {
                 drishti_bala += match planet.name.as_str() {
                     "Jupiter" => 20.0,
                     "Mars" => 15.0,
@@ -1155,7 +1315,7 @@ pub fn calculate_bhava_bala(planets: &[PlanetPosition], asc: f64) -> Result<Vec<
         let total = adhipati_bala + dig_bala + drishti_bala;
 
         bhavas.push(BhavaBala {
-            house_number: house_num as u8,
+            house_number: house_num,
             bhava_adhipati_bala: adhipati_bala,
             bhava_digbala: dig_bala,
             bhava_drishti_bala: drishti_bala,
@@ -1169,12 +1329,12 @@ pub fn calculate_bhava_bala(planets: &[PlanetPosition], asc: f64) -> Result<Vec<
 pub fn calculate_strength_metrics(
     planets: &[PlanetPosition],
     asc: f64,
-    jd: f64,
+    jd_ut: f64,
 ) -> Result<StrengthMetrics> {
     // Calculate Shadbala
     let mut shadbala = HashMap::new();
     for planet in planets {
-        let strength = calculate_shadbala(planet, jd, asc)?;
+        let strength = calculate_shadbala(planet, jd_ut, asc)?;
         shadbala.insert(planet.name.clone(), strength);
     }
 
@@ -1216,7 +1376,6 @@ pub fn calculate_planet_strength(
     asc: f64,
 ) -> Result<f64> {
     let mut strength = 0.0;
-    let sign = (planet.sidereal_long_deg / 30.0).floor() as i32 % 12;
 
     // Natural strength
     strength += match planet.name.as_str() {
@@ -1237,7 +1396,7 @@ pub fn calculate_planet_strength(
     };
 
     // Positional strength
-    let house = ((planet.sidereal_long_deg - asc) / 30.0).floor() as i32 % 12;
+    let house = whole_sign_house(planet.sidereal_long_deg, asc) as i32 - 1;
     strength += match house {
         0 | 3 | 6 | 9 => 1.0,   // Angular houses
         1 | 4 | 7 | 10 => 0.75, // Succedent houses
@@ -1291,11 +1450,23 @@ pub struct DashaPeriod {
     pub years: f64,
 }
 
+impl DashaPeriod {
+    /// Start of the period as a timestamp in `tz`.
+    pub fn start_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.start, tz)
+    }
+
+    /// End of the period as a timestamp in `tz`.
+    pub fn end_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.end, tz)
+    }
+}
+
 impl PlanetInfo {
-    pub fn new(position: PlanetPosition, asc: &f64, jd: f64) -> Result<Self> {
+    pub fn new(position: PlanetPosition, asc: &f64, jd_ut: f64) -> Result<Self> {
         Ok(PlanetInfo {
             dignity: calculate_dignity(&position)?,
-            strength: calculate_shadbala(&position, jd, *asc)?,
+            strength: calculate_shadbala(&position, jd_ut, *asc)?,
             relationships: calculate_relationships(&position)?,
             basic_info: position,
         })
@@ -1324,24 +1495,7 @@ pub fn calculate_houses(asc: f64, planets: &[PlanetPosition]) -> Result<Houses>
 /// starting from the ascendant's sign.
 /// Returns an array of 12 house cusps in sidereal degrees [0..360)
 pub fn calculate_whole_sign_houses(asc_sid_deg: f64) -> [f64; 12] {
-    // Normalize ascendant to [0, 360)
-    let asc_normalized = asc_sid_deg.rem_euclid(360.0);
-
-    // Find the start of the ascendant's sign
-    let sign_index = (asc_normalized / 30.0).floor();
-    let sign_start_deg = sign_index * 30.0;
-
-    // Initialize house cusps array
-    let mut house_cusps = [0.0; 12];
-
-    // Calculate each house cusp
-    for i in 0..12 {
-        // Each house starts at a sign boundary
-        let house_start = (sign_start_deg + (i as f64 * 30.0)).rem_euclid(360.0);
-        house_cusps[i] = house_start;
-    }
-
-    house_cusps
+    whole_sign_cusps(asc_sid_deg)
 }
 
 /// Calculate all divisional charts
@@ -1381,7 +1535,9 @@ pub fn calculate_dwadasamsa_chart(planets: &[PlanetPosition], asc: f64) -> Resul
         let rashi_pos = RashiPosition {
             rashi: dwadasamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1402,7 +1558,9 @@ pub fn calculate_panchamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result
         let rashi_pos = RashiPosition {
             rashi: panchamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1411,7 +1569,7 @@ pub fn calculate_panchamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result
     Ok(Chart {
         name: "PANCHAMSA [10]".to_string(),
         planets: planet_positions,
-        houses: compute_whole_sign_houses(asc),
+        houses: calculate_whole_sign_houses(asc),
     })
 }
 
@@ -1423,7 +1581,9 @@ pub fn calculate_shashthamsa_chart(planets: &[PlanetPosition], asc: f64) -> Resu
         let rashi_pos = RashiPosition {
             rashi: shashtamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1444,7 +1604,9 @@ pub fn calculate_saptamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<
         let rashi_pos = RashiPosition {
             rashi: saptamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1465,7 +1627,9 @@ pub fn calculate_ashtamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<
         let rashi_pos = RashiPosition {
             rashi: ashtamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1486,7 +1650,9 @@ pub fn calculate_navamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<C
         let rashi_pos = RashiPosition {
             rashi: navamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1507,7 +1673,9 @@ pub fn calculate_dasamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<C
         let rashi_pos = RashiPosition {
             rashi: dasamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1530,7 +1698,9 @@ pub fn calculate_rudramsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(rudramsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1551,7 +1721,9 @@ pub fn calculate_shodasamsa_chart(planets: &[PlanetPosition], asc: f64) -> Resul
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(shodasamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1572,7 +1744,9 @@ pub fn calculate_vimsamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(vimsamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1593,7 +1767,9 @@ pub fn calculate_chaturvimsamsa_chart(planets: &[PlanetPosition], asc: f64) -> R
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(chaturvimsamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1614,7 +1790,9 @@ pub fn calculate_bhamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<Ch
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(bhamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1629,13 +1807,15 @@ pub fn calculate_bhamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<Ch
 pub fn calculate_trimsamsa_chart(planets: &[PlanetPosition], asc: f64) -> Result<Chart> {
     let mut planet_positions = HashMap::new();
     for planet in planets {
-        let trimsamsa = ((planet.sidereal_long_deg / (30.0 / 30.0)).floor() as i32
+        let trimsamsa = (planet.sidereal_long_deg.floor() as i32
             + ((planet.sidereal_long_deg / 30.0).floor() as i32 * 30))
             % 12;
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(trimsamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1656,7 +1836,9 @@ pub fn calculate_khavedamsa_chart(planets: &[PlanetPosition], asc: f64) -> Resul
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(khavedamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1677,7 +1859,9 @@ pub fn calculate_akshavedamsa_chart(planets: &[PlanetPosition], asc: f64) -> Res
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(akshavedamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1713,7 +1897,9 @@ pub fn calculate_hora_chart(planets: &[PlanetPosition], asc: f64) -> Result<Char
         let rashi_pos = RashiPosition {
             rashi: hora_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1734,7 +1920,9 @@ pub fn calculate_drekkana_chart(planets: &[PlanetPosition], asc: f64) -> Result<
         let rashi_pos = RashiPosition {
             rashi: drekkana_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1755,7 +1943,9 @@ pub fn calculate_chaturthamsa_chart(planets: &[PlanetPosition], asc: f64) -> Res
         let rashi_pos = RashiPosition {
             rashi: chaturtamsa_rashi,
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1778,7 +1968,9 @@ pub fn calculate_shashtyamsa_chart(planets: &[PlanetPosition], asc: f64) -> Resu
         let rashi_pos = RashiPosition {
             rashi: get_rasi_name(shashtyamsa),
             degree: planet.sidereal_long_deg % 30.0,
-            nakshatra: compute_nakshatra(planet.sidereal_long_deg).1.to_string(),
+            nakshatra: nakshatra_from_longitude(planet.sidereal_long_deg)
+                .1
+                .to_string(),
             pada: ((planet.sidereal_long_deg % 13.333333) / 3.333333).floor() as u8 + 1,
         };
         planet_positions.insert(planet.name.clone(), rashi_pos);
@@ -1796,7 +1988,7 @@ pub fn calculate_rashi_position(longitude: f64) -> Result<RashiPosition> {
     Ok(RashiPosition {
         rashi: compute_rasi(longitude),
         degree: longitude % 30.0,
-        nakshatra: compute_nakshatra(longitude).1.to_string(),
+        nakshatra: nakshatra_from_longitude(longitude).1.to_string(),
         pada: ((longitude % 13.333333) / 3.333333).floor() as u8 + 1,
     })
 }
@@ -1877,8 +2069,8 @@ pub fn calculate_relationships(planet: &PlanetPosition) -> Result<PlanetaryRelat
     };
 
     // Calculate temporary relationships based on current positions
-    let mut temp_friends = Vec::new();
-    let mut temp_enemies = Vec::new();
+    let temp_friends = Vec::new();
+    let temp_enemies = Vec::new();
 
     // This would need the positions of all planets to calculate
     // temporary relationships based on their relative positions
@@ -1892,88 +2084,53 @@ pub fn calculate_relationships(planet: &PlanetPosition) -> Result<PlanetaryRelat
     })
 }
 
-pub fn calculate_vimsottari_dasha(moon_longitude: f64, birth_jd: f64) -> Result<VimshottariDasha> {
-    // Dasha periods in years for each planet
-    const DASHA_YEARS: [(f64, &str); 9] = [
-        (6.0, "Sun"),
-        (10.0, "Moon"),
-        (7.0, "Mars"),
-        (18.0, "Rahu"),
-        (16.0, "Jupiter"),
-        (19.0, "Saturn"),
-        (17.0, "Mercury"),
-        (20.0, "Ketu"),
-        (7.0, "Venus"),
-    ];
-    const TOTAL_CYCLE: f64 = 120.0; // Total years in Vimshottari cycle
-
+/// Vimshottari dasha years of each lord, in the order the periods run.
+const DASHA_YEARS: [(f64, &str); 9] = [
+    (7.0, "Ketu"),
+    (20.0, "Venus"),
+    (6.0, "Sun"),
+    (10.0, "Moon"),
+    (7.0, "Mars"),
+    (18.0, "Rahu"),
+    (16.0, "Jupiter"),
+    (19.0, "Saturn"),
+    (17.0, "Mercury"),
+];
+const DASHA_CYCLE_YEARS: f64 = 120.0;
+const DAYS_PER_DASHA_YEAR: f64 = 365.25;
+
+/// Vimshottari dasha running at birth. `birth_jd_ut` is JD(UT) and the
+/// period bounds are returned on the same scale.
+pub fn calculate_vimsottari_dasha(
+    moon_longitude: f64,
+    birth_jd_ut: f64,
+) -> Result<VimshottariDasha> {
     // Calculate nakshatra and progression
-    let nak_deg = 13.333333; // Each nakshatra is 13°20'
-    let nak_idx = (moon_longitude / nak_deg).floor() as usize % 27;
-    let prog = (moon_longitude % nak_deg) / nak_deg;
-
-    // Starting lord for each nakshatra
-    let nakshatra_lords = [
-        "Ketu", "Venus", "Sun", "Moon", "Mars", "Rahu", "Jupiter", "Saturn", "Mercury", "Ketu",
-        "Venus", "Sun", "Moon", "Mars", "Rahu", "Jupiter", "Saturn", "Mercury", "Ketu", "Venus",
-        "Sun", "Moon", "Mars", "Rahu", "Jupiter", "Saturn", "Mercury",
-    ];
+    let nak_deg = 360.0 / 27.0; // Each nakshatra is 13°20'
+    let prog = normalize_degrees(moon_longitude) % nak_deg / nak_deg;
 
     // Find starting dasha lord
-    let start_lord = nakshatra_lords[nak_idx];
-    let mut lord_idx = DASHA_YEARS
+    let start_lord = nakshatra_lord(moon_longitude);
+    let lord_idx = DASHA_YEARS
         .iter()
         .position(|&(_, lord)| lord == start_lord)
         .ok_or_else(|| VedicError::CalculationError("Invalid dasha lord".to_string()))?;
 
-    // Calculate elapsed duration in current mahadasha
-    let elapsed_years = DASHA_YEARS[lord_idx].0 * prog;
-    let start_jd = birth_jd - (elapsed_years * 365.25);
-    let end_jd = start_jd + (DASHA_YEARS[lord_idx].0 * 365.25);
-
-    // Calculate current periods
+    // The balance of the mahadasha left at birth
+    let (years, planet) = DASHA_YEARS[lord_idx];
+    let start_jd = birth_jd_ut - years * prog * DAYS_PER_DASHA_YEAR;
     let maha_dasha = DashaPeriod {
-        planet: DASHA_YEARS[lord_idx].1.to_string(),
+        planet: planet.to_string(),
         start: start_jd,
-        end: end_jd,
-        years: DASHA_YEARS[lord_idx].0,
+        end: start_jd + years * DAYS_PER_DASHA_YEAR,
+        years,
     };
 
-    // Calculate antardasha
-    lord_idx = (lord_idx + 1) % 9;
-    let antar_years = DASHA_YEARS[lord_idx].0 * maha_dasha.years / TOTAL_CYCLE;
-    let antar_end = start_jd + (antar_years * 365.25);
-
-    let antara_dasha = DashaPeriod {
-        planet: DASHA_YEARS[lord_idx].1.to_string(),
-        start: start_jd,
-        end: antar_end,
-        years: antar_years,
-    };
-
-    // Calculate pratyantara
-    lord_idx = (lord_idx + 1) % 9;
-    let prat_years = DASHA_YEARS[lord_idx].0 * antar_years / TOTAL_CYCLE;
-    let prat_end = start_jd + (prat_years * 365.25);
-
-    let pratyantara_dasha = DashaPeriod {
-        planet: DASHA_YEARS[lord_idx].1.to_string(),
-        start: start_jd,
-        end: prat_end,
-        years: prat_years,
-    };
-
-    // Calculate sookshma
-    lord_idx = (lord_idx + 1) % 9;
-    let sook_years = DASHA_YEARS[lord_idx].0 * prat_years / TOTAL_CYCLE;
-    let sook_end = start_jd + (sook_years * 365.25);
-
-    let sookshma_dasha = DashaPeriod {
-        planet: DASHA_YEARS[lord_idx].1.to_string(),
-        start: start_jd,
-        end: sook_end,
-        years: sook_years,
-    };
+    // Each sub-period divides its parent in the same proportions, starting
+    // with the parent's own lord
+    let (antar_idx, antara_dasha) = dasha_sub_period(&maha_dasha, lord_idx, birth_jd_ut);
+    let (prat_idx, pratyantara_dasha) = dasha_sub_period(&antara_dasha, antar_idx, birth_jd_ut);
+    let (_, sookshma_dasha) = dasha_sub_period(&pratyantara_dasha, prat_idx, birth_jd_ut);
 
     Ok(VimshottariDasha {
         maha_dasha,
@@ -1983,10 +2140,39 @@ pub fn calculate_vimsottari_dasha(moon_longitude: f64, birth_jd: f64) -> Result<
     })
 }
 
-pub fn calculate_shadbala(planet: &PlanetPosition, jd: f64, asc: f64) -> Result<PlanetaryStrength> {
+/// The sub-period of `parent`, whose lord is `DASHA_YEARS[parent_idx]`,
+/// running at `jd`, with its lord's index.
+fn dasha_sub_period(parent: &DashaPeriod, parent_idx: usize, jd: f64) -> (usize, DashaPeriod) {
+    let mut start = parent.start;
+    let mut step = 0;
+    loop {
+        let idx = (parent_idx + step) % 9;
+        let (lord_years, planet) = DASHA_YEARS[idx];
+        let years = lord_years * parent.years / DASHA_CYCLE_YEARS;
+        let end = start + years * DAYS_PER_DASHA_YEAR;
+        if jd < end || step == 8 {
+            return (
+                idx,
+                DashaPeriod {
+                    planet: planet.to_string(),
+                    start,
+                    end,
+                    years,
+                },
+            );
+        }
+        start = end;
+        step += 1;
+    }
+}
+
+pub fn calculate_shadbala(
+    planet: &PlanetPosition,
+    jd_ut: f64,
+    asc: f64,
+) -> Result<PlanetaryStrength> {
     // 1. Sthana Bala (Positional Strength)
     let mut sthan_bala = 0.0;
-    let sign = (planet.sidereal_long_deg / 30.0).floor() as i32 % 12;
 
     // Add strength based on dignity
     let dignity = calculate_dignity(planet)?;
@@ -2010,66 +2196,29 @@ pub fn calculate_shadbala(planet: &PlanetPosition, jd: f64, asc: f64) -> Result<
     }
 
     // 2. Dig Bala (Directional Strength)
-    let mut dig_bala = 0.0;
-    let house = ((planet.sidereal_long_deg - asc) / 30.0).floor() as i32 % 12;
-
-    match planet.name.as_str() {
-        "Sun" => {
-            if house == 9 {
-                dig_bala = 1.0
-            }
-        } // Strong in 10th house
-        "Moon" => {
-            if house == 3 {
-                dig_bala = 1.0
-            }
-        } // Strong in 4th house
-        "Mars" => {
-            if house == 9 {
-                dig_bala = 1.0
-            }
-        } // Strong in 10th house
-        "Mercury" => {
-            if house == 0 {
-                dig_bala = 1.0
-            }
-        } // Strong in 1st house
-        "Jupiter" => {
-            if house == 0 {
-                dig_bala = 1.0
-            }
-        } // Strong in 1st house
-        "Venus" => {
-            if house == 3 {
-                dig_bala = 1.0
-            }
-        } // Strong in 4th house
-        "Saturn" => {
-            if house == 6 {
-                dig_bala = 1.0
-            }
-        } // Strong in 7th house
-        _ => {}
-    }
+    let house = whole_sign_house(planet.sidereal_long_deg, asc) as i32 - 1;
+
+    let dig_bala = match (planet.name.as_str(), house) {
+        ("Sun", 9) => 1.0,     // Strong in 10th house
+        ("Moon", 3) => 1.0,    // Strong in 4th house
+        ("Mars", 9) => 1.0,    // Strong in 10th house
+        ("Mercury", 0) => 1.0, // Strong in 1st house
+        ("Jupiter", 0) => 1.0, // Strong in 1st house
+        ("Venus", 3) => 1.0,   // Strong in 4th house
+        ("Saturn", 6) => 1.0,  // Strong in 7th house
+        _ => 0.0,
+    };
 
     // 3. Kala Bala (Temporal Strength)
     let mut kala_bala = 0.0;
 
     // Day/Night strength
-    let lst = local_sidereal_time(jd, 0.0); // Use 0.0 for longitude as we want GMT
+    let lst = local_sidereal_time(jd_ut, 0.0); // Use 0.0 for longitude as we want GMT
     let is_day = lst >= 180.0;
 
     match planet.name.as_str() {
-        "Sun" | "Jupiter" | "Saturn" => {
-            if is_day {
-                kala_bala += 0.5
-            }
-        }
-        "Moon" | "Venus" | "Mars" => {
-            if !is_day {
-                kala_bala += 0.5
-            }
-        }
+        "Sun" | "Jupiter" | "Saturn" if is_day => kala_bala += 0.5,
+        "Moon" | "Venus" | "Mars" if !is_day => kala_bala += 0.5,
         _ => {}
     }
 
@@ -2177,7 +2326,7 @@ pub fn determine_house_lords(cusps: &[f64; 12]) -> Result<Vec<String>> {
 }
 
 /// Calculate all applicable Yogas in the chart
-pub fn calculate_all_yogas(planets: &[PlanetPosition], asc: f64) -> Result<Vec<Yoga>> {
+pub fn calculate_all_yogas(planets: &[PlanetPosition], _asc: f64) -> Result<Vec<Yoga>> {
     let mut yogas = Vec::new();
 
     // Calculate Raja Yogas
@@ -2258,7 +2407,10 @@ pub fn is_trikona_lord(planet: &str, house: i32) -> bool {
     )
 }
 
-pub fn calculate_raja_yoga_strength(planet1: &PlanetPosition, planet2: &PlanetPosition) -> Result<f64> {
+pub fn calculate_raja_yoga_strength(
+    planet1: &PlanetPosition,
+    planet2: &PlanetPosition,
+) -> Result<f64> {
     let mut strength: f64 = 1.0;
 
     // Add strength if planets are in mutual reception
@@ -2279,7 +2431,7 @@ pub fn calculate_raja_yoga_strength(planet1: &PlanetPosition, planet2: &PlanetPo
         strength -= 0.5;
     }
 
-    Ok(strength.max(0.0).min(2.0))
+    Ok(strength.clamp(0.0, 2.0))
 }
 
 pub fn check_dhana_yogas(planets: &[PlanetPosition], yogas: &mut Vec<Yoga>) -> Result<()> {
@@ -2313,57 +2465,47 @@ pub fn check_mahapurusha_yogas(planets: &[PlanetPosition], yogas: &mut Vec<Yoga>
         let sign = (planet.sidereal_long_deg / 30.0).floor() as i32 % 12;
 
         // Only check if planet is in a Kendra
-        if kendras.contains(&(house as i32)) {
+        if kendras.contains(&house) {
             match planet.name.as_str() {
-                "Mars" => {
-                    // Ruchaka Yoga - Mars in own sign (Aries or Scorpio) or exalted (Capricorn)
-                    if sign == 0 || sign == 7 || sign == 9 {
-                        yogas.push(Yoga {
-                            name: "Ruchaka Yoga".to_string(),
-                            description: "Mars in own/exaltation sign and Kendra".to_string(),
-                            strength: 1.0,
-                        });
-                    }
+                // Ruchaka Yoga - Mars in own sign (Aries or Scorpio) or exalted (Capricorn)
+                "Mars" if sign == 0 || sign == 7 || sign == 9 => {
+                    yogas.push(Yoga {
+                        name: "Ruchaka Yoga".to_string(),
+                        description: "Mars in own/exaltation sign and Kendra".to_string(),
+                        strength: 1.0,
+                    });
                 }
-                "Mercury" => {
-                    // Bhadra Yoga - Mercury in own sign (Gemini or Virgo) or exalted (Virgo)
-                    if sign == 2 || sign == 5 {
-                        yogas.push(Yoga {
-                            name: "Bhadra Yoga".to_string(),
-                            description: "Mercury in own/exaltation sign and Kendra".to_string(),
-                            strength: 1.0,
-                        });
-                    }
+                // Bhadra Yoga - Mercury in own sign (Gemini or Virgo) or exalted (Virgo)
+                "Mercury" if sign == 2 || sign == 5 => {
+                    yogas.push(Yoga {
+                        name: "Bhadra Yoga".to_string(),
+                        description: "Mercury in own/exaltation sign and Kendra".to_string(),
+                        strength: 1.0,
+                    });
                 }
-                "Jupiter" => {
-                    // Hamsa Yoga - Jupiter in own sign (Sagittarius or Pisces) or exalted (Cancer)
-                    if sign == 8 || sign == 11 || sign == 3 {
-                        yogas.push(Yoga {
-                            name: "Hamsa Yoga".to_string(),
-                            description: "Jupiter in own/exaltation sign and Kendra".to_string(),
-                            strength: 1.0,
-                        });
-                    }
+                // Hamsa Yoga - Jupiter in own sign (Sagittarius or Pisces) or exalted (Cancer)
+                "Jupiter" if sign == 8 || sign == 11 || sign == 3 => {
+                    yogas.push(Yoga {
+                        name: "Hamsa Yoga".to_string(),
+                        description: "Jupiter in own/exaltation sign and Kendra".to_string(),
+                        strength: 1.0,
+                    });
                 }
-                "Venus" => {
-                    // Malavya Yoga - Venus in own sign (Taurus or Libra) or exalted (Pisces)
-                    if sign == 1 || sign == 6 || sign == 11 {
-                        yogas.push(Yoga {
-                            name: "Malavya Yoga".to_string(),
-                            description: "Venus in own/exaltation sign and Kendra".to_string(),
-                            strength: 1.0,
-                        });
-                    }
+                // Malavya Yoga - Venus in own sign (Taurus or Libra) or exalted (Pisces)
+                "Venus" if sign == 1 || sign == 6 || sign == 11 => {
+                    yogas.push(Yoga {
+                        name: "Malavya Yoga".to_string(),
+                        description: "Venus in own/exaltation sign and Kendra".to_string(),
+                        strength: 1.0,
+                    });
                 }
-                "Saturn" => {
-                    // Sasa Yoga - Saturn in own sign (Capricorn or Aquarius) or exalted (Libra)
-                    if sign == 9 || sign == 10 || sign == 6 {
-                        yogas.push(Yoga {
-                            name: "Sasa Yoga".to_string(),
-                            description: "Saturn in own/exaltation sign and Kendra".to_string(),
-                            strength: 1.0,
-                        });
-                    }
+                // Sasa Yoga - Saturn in own sign (Capricorn or Aquarius) or exalted (Libra)
+                "Saturn" if sign == 9 || sign == 10 || sign == 6 => {
+                    yogas.push(Yoga {
+                        name: "Sasa Yoga".to_string(),
+                        description: "Saturn in own/exaltation sign and Kendra".to_string(),
+                        strength: 1.0,
+                    });
                 }
                 _ => {}
             }
@@ -2374,7 +2516,7 @@ pub fn check_mahapurusha_yogas(planets: &[PlanetPosition], yogas: &mut Vec<Yoga>
 
 pub fn check_nabhasa_yogas(planets: &[PlanetPosition], yogas: &mut Vec<Yoga>) -> Result<()> {
     // Check for Rajju Yoga (planets in successive houses)
-    let mut houses_occupied = vec![false; 12];
+    let mut houses_occupied = [false; 12];
     for planet in planets {
         let house = (planet.sidereal_long_deg / 30.0).floor() as usize % 12;
         houses_occupied[house] = true;
@@ -2418,19 +2560,46 @@ pub fn check_nabhasa_yogas(planets: &[PlanetPosition], yogas: &mut Vec<Yoga>) ->
     Ok(())
 }
 
-pub fn compute_all_planets(jd: f64) -> Result<Vec<PlanetPosition>> {
+/// Options for [`compute_all_planets_with_config`]. The default gives mean
+/// nodes, geometric positions and the Lahiri ayanamsa, as
+/// [`compute_all_planets`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct EphemerisConfig {
+    pub node_type: NodeType,
+    pub apparent_place: ApparentPlace,
+    pub ayanamsa: Ayanamsa,
+}
+
+/// Sidereal positions of the nine grahas at `jd_tt`, JD(TT); see
+/// [`BirthData::to_jd_tt`].
+pub fn compute_all_planets(jd_tt: f64) -> Result<Vec<PlanetPosition>> {
+    compute_all_planets_with_config(jd_tt, &EphemerisConfig::default())
+}
+
+/// Same as [`compute_all_planets`], with the node model, the apparent-place
+/// corrections and the ayanamsa taken from `config`.
+pub fn compute_all_planets_with_config(
+    jd_tt: f64,
+    config: &EphemerisConfig,
+) -> Result<Vec<PlanetPosition>> {
     let mut positions = Vec::new();
+    let place = config.apparent_place;
+    let ayanamsa = config.ayanamsa;
 
     // Calculate each planet's position with error handling
-    let sun = compute_planet_position("Sun", jd, get_sun(jd));
-    let moon = compute_planet_position("Moon", jd, get_moon(jd));
-    let rahu = compute_planet_position("Rahu", jd, get_rahu(jd));
-    let ketu = compute_planet_position("Ketu", jd, get_ketu(jd));
-    let mercury = compute_planet_position("Mercury", jd, get_mercury(jd));
-    let venus = compute_planet_position("Venus", jd, get_venus(jd));
-    let mars = compute_planet_position("Mars", jd, get_mars(jd));
-    let jupiter = compute_planet_position("Jupiter", jd, get_jupiter(jd));
-    let saturn = compute_planet_position("Saturn", jd, get_saturn(jd));
+    let sun = compute_apparent_position("Sun", jd_tt, place, ayanamsa)?;
+    let moon = compute_apparent_position("Moon", jd_tt, place, ayanamsa)?;
+    let (mut rahu, mut ketu) = compute_lunar_nodes(jd_tt, config.node_type, ayanamsa);
+    if place.nutation {
+        let (dpsi, _) = nutation(jd_tt);
+        rahu.sidereal_long_deg = normalize_degrees(rahu.sidereal_long_deg + dpsi);
+        ketu.sidereal_long_deg = normalize_degrees(ketu.sidereal_long_deg + dpsi);
+    }
+    let mercury = compute_apparent_position("Mercury", jd_tt, place, ayanamsa)?;
+    let venus = compute_apparent_position("Venus", jd_tt, place, ayanamsa)?;
+    let mars = compute_apparent_position("Mars", jd_tt, place, ayanamsa)?;
+    let jupiter = compute_apparent_position("Jupiter", jd_tt, place, ayanamsa)?;
+    let saturn = compute_apparent_position("Saturn", jd_tt, place, ayanamsa)?;
 
     // Add positions in order of traditional importance
     positions.push(sun);
@@ -2515,3 +2684,86 @@ pub fn is_debilitated(planet: &PlanetPosition) -> bool {
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekday_name_follows_weekday_index() {
+        // 2000 January 1 was a Saturday
+        for jd in [2451544.5, 2451545.0, 2451545.49] {
+            assert_eq!(weekday_index(jd), 6);
+            assert_eq!(weekday_string(jd), "Śani");
+        }
+        assert_eq!(weekday_string(2451545.5), "Ravi");
+        // a Sunday long before JD 0, where truncation would miscount
+        let sunday = 2451545.5 - 7.0 * 400_000.0;
+        assert_eq!(weekday_index(sunday), 0);
+        assert_eq!(weekday_string(sunday + 1.0), "Soma");
+    }
+
+    #[test]
+    fn located_panchanga_keeps_the_vara_until_sunrise() {
+        // Monday 2024 January 1, 06:00 IST in Delhi, before the 07:14 sunrise
+        let jd = 2460310.5 + 0.5 / 24.0;
+        const ALMANAC: RiseSetOptions = RiseSetOptions::ALMANAC;
+        assert_eq!(compute_panchanga(jd, Ayanamsa::Lahiri).weekday, "Soma");
+        let panchanga =
+            compute_panchanga_at(jd, 77.209, 28.6139, Ayanamsa::Lahiri, ALMANAC).unwrap();
+        assert_eq!(panchanga.weekday, "Ravi");
+        let later =
+            compute_panchanga_at(jd + 0.25, 77.209, 28.6139, Ayanamsa::Lahiri, ALMANAC).unwrap();
+        assert_eq!(later.weekday, "Soma");
+    }
+
+    #[test]
+    fn vimshottari_dasha_runs_in_sequence_from_the_balance_at_birth() {
+        // Moon at 2° Taurus: Mrigashira (Mars) is 65% gone, so 4.55 of the
+        // 7 years of Mars have run. Its antaras from Mars take 0.4083, 1.05,
+        // 0.9333, 1.1083 and 0.9917 years, leaving Ketu (4.4917 to 4.9)
+        // running, and within it Venus after 0.0238 years of Ketu.
+        let birth = 2451545.0;
+        let dasha = calculate_vimsottari_dasha(62.0, birth).unwrap();
+        let years = |jd: f64| (jd - dasha.maha_dasha.start) / 365.25;
+
+        assert_eq!(dasha.maha_dasha.planet, "Mars");
+        assert!((years(birth) - 4.55).abs() < 1e-9);
+        assert!((dasha.maha_dasha.years - 7.0).abs() < 1e-12);
+
+        assert_eq!(dasha.antara_dasha.planet, "Ketu");
+        assert!((years(dasha.antara_dasha.start) - 4.491667).abs() < 1e-6);
+        assert!((years(dasha.antara_dasha.end) - 4.9).abs() < 1e-6);
+
+        assert_eq!(dasha.pratyantara_dasha.planet, "Venus");
+        let into_antara = (dasha.pratyantara_dasha.start - dasha.antara_dasha.start) / 365.25;
+        assert!((into_antara - 7.0 * 0.408333 / 120.0).abs() < 1e-6);
+        assert_eq!(dasha.sookshma_dasha.planet, "Rahu");
+
+        for period in [
+            &dasha.antara_dasha,
+            &dasha.pratyantara_dasha,
+            &dasha.sookshma_dasha,
+        ] {
+            assert!(period.start <= birth && birth < period.end);
+        }
+    }
+
+    #[test]
+    fn vimshottari_dasha_of_venus_lasts_twenty_years() {
+        // Moon at the start of Bharani: a full Venus mahadasha, opening with
+        // Venus-Venus-Venus-Venus
+        let dasha = calculate_vimsottari_dasha(360.0 / 27.0 + 1e-9, 2451545.0).unwrap();
+        for period in [
+            &dasha.maha_dasha,
+            &dasha.antara_dasha,
+            &dasha.pratyantara_dasha,
+            &dasha.sookshma_dasha,
+        ] {
+            assert_eq!(period.planet, "Venus");
+            assert!((period.start - 2451545.0).abs() < 1e-3);
+        }
+        assert!((dasha.maha_dasha.years - 20.0).abs() < 1e-12);
+        assert!((dasha.antara_dasha.years - 20.0 / 6.0).abs() < 1e-12);
+    }
+}
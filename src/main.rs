@@ -1,8 +1,8 @@
 use astro::*;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{TimeZone, Utc};
 use chrono_tz::Asia::Kolkata;
-use prettytable::{format::{self, TableFormat}, Cell, Row, Table};
 use colored::*;
+use prettytable::{format, Cell, Row, Table};
 
 fn main() -> Result<()> {
     // Create birth data for Aghil
@@ -17,37 +17,45 @@ fn main() -> Result<()> {
 
     let birth_data = aghils_birth_data.clone();
     let jd = birth_data.to_jd()?;
-    let ayanamsa = calculate_ayanamsa(jd);
-    let asc_sid_deg = compute_ascendant_sidereal(&birth_data);
-    let planets = compute_all_planets(jd)?;
- 
-
+    let jd_tt = birth_data.to_jd_tt()?;
+    let config = EphemerisConfig {
+        ayanamsa: Ayanamsa::Lahiri,
+        ..EphemerisConfig::default()
+    };
+    // sunrise as the almanacs give it; RiseSetOptions::HINDU for the disc's centre
+    let sunrise_options = RiseSetOptions::ALMANAC;
+    let asc_sid_deg = compute_ascendant_sidereal(&birth_data, config.ayanamsa)?;
+    let angles = compute_angles(&birth_data, config.ayanamsa)?;
+    let planets = compute_all_planets_with_config(jd_tt, &config)?;
+    let chalit = compute_bhava_chalit(
+        &birth_data,
+        &planets,
+        BhavaDivision::Sripati,
+        config.ayanamsa,
+    )?;
 
     println!("\n{}", "=== VEDIC BIRTH CHART ANALYSIS ===".bold());
     println!("{}", "--------------------------------".bold());
-    
+
     // Basic Information Table
     let mut basic_info = Table::new();
     basic_info.set_format(*format::consts::FORMAT_BOX_CHARS);
-    basic_info.add_row(Row::new(vec![
-        Cell::new("Name"),
-        Cell::new("AGHIL MOHAN"),
-    ]));
+    basic_info.add_row(Row::new(vec![Cell::new("Name"), Cell::new("AGHIL MOHAN")]));
     basic_info.add_row(Row::new(vec![
         Cell::new("Date"),
         Cell::new("June 18th, 1991"),
     ]));
-    basic_info.add_row(Row::new(vec![
-        Cell::new("Time"),
-        Cell::new("07:10 AM IST"),
-    ]));
+    basic_info.add_row(Row::new(vec![Cell::new("Time"), Cell::new("07:10 AM IST")]));
     basic_info.add_row(Row::new(vec![
         Cell::new("Place"),
         Cell::new("Calicut, Kerala, India"),
     ]));
     basic_info.add_row(Row::new(vec![
         Cell::new("Coordinates"),
-        Cell::new(&format!("{}°E, {}°N", birth_data.longitude, birth_data.latitude)),
+        Cell::new(&format!(
+            "{}°E, {}°N",
+            birth_data.longitude, birth_data.latitude
+        )),
     ]));
     basic_info.printstd();
 
@@ -59,10 +67,40 @@ fn main() -> Result<()> {
         Cell::new("Julian Day"),
         Cell::new(&format!("{:.6}", jd)),
     ]));
+    tech_details.add_row(Row::new(vec![
+        Cell::new("Delta T"),
+        Cell::new(&format!("{:.1} s", delta_t(jd))),
+    ]));
     tech_details.add_row(Row::new(vec![
         Cell::new("Ayanamsa"),
-        Cell::new(&format!("{:.6}°", ayanamsa * RAD_TO_DEG)),
+        Cell::new(&format!(
+            "{:?} {:.6}°",
+            config.ayanamsa,
+            config.ayanamsa.degrees(jd_tt)
+        )),
     ]));
+    let sunrise_jd = previous_sunrise(
+        jd,
+        birth_data.longitude,
+        birth_data.latitude,
+        sunrise_options,
+    )?;
+    let sunset_jd = sunset(
+        sunrise_jd,
+        birth_data.longitude,
+        birth_data.latitude,
+        sunrise_options,
+    )?;
+    for (label, event_jd) in [("Sunrise", sunrise_jd), ("Sunset", sunset_jd)] {
+        tech_details.add_row(Row::new(vec![
+            Cell::new(label),
+            Cell::new(
+                &jd_to_datetime(event_jd, &Kolkata)?
+                    .format("%H:%M:%S")
+                    .to_string(),
+            ),
+        ]));
+    }
     tech_details.printstd();
 
     // Ascendant Details
@@ -70,16 +108,40 @@ fn main() -> Result<()> {
     let (asc_rasi, asc_deg, asc_min, asc_sec) = rasi_details(asc_sid_deg);
     let mut asc_table = Table::new();
     asc_table.set_format(*format::consts::FORMAT_BOX_CHARS);
-    asc_table.add_row(Row::new(vec![
-        Cell::new("Sign"),
-        Cell::new(&asc_rasi),
-    ]));
+    asc_table.add_row(Row::new(vec![Cell::new("Sign"), Cell::new(&asc_rasi)]));
     asc_table.add_row(Row::new(vec![
         Cell::new("Position"),
         Cell::new(&format!("{}°{}'{:.1}\"", asc_deg, asc_min, asc_sec)),
     ]));
+    asc_table.add_row(Row::new(vec![
+        Cell::new("Nakshatra"),
+        Cell::new(&format!(
+            "{} (lord {})",
+            angles.ascendant_nakshatra, angles.ascendant_nakshatra_lord
+        )),
+    ]));
     asc_table.printstd();
 
+    // Angles
+    println!("\n{}", "Angles".bold());
+    let mut angle_table = Table::new();
+    angle_table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    for (label, longitude) in [
+        ("Descendant", angles.descendant),
+        ("Midheaven (MC)", angles.midheaven),
+        ("Imum Coeli (IC)", angles.imum_coeli),
+        ("Vertex", angles.vertex),
+        ("Anti-Vertex", angles.anti_vertex),
+        ("East Point", angles.east_point),
+    ] {
+        let (rasi, deg, min, sec) = rasi_details(longitude);
+        angle_table.add_row(Row::new(vec![
+            Cell::new(label),
+            Cell::new(&format!("{} {}°{}'{:.1}\"", rasi, deg, min, sec)),
+        ]));
+    }
+    angle_table.printstd();
+
     // Planetary Positions with House Placements
     println!("\n{}", "Planetary Positions".bold());
     let mut planet_table = Table::new();
@@ -88,15 +150,21 @@ fn main() -> Result<()> {
         Cell::new("Sign").style_spec("b"),
         Cell::new("Position").style_spec("b"),
         Cell::new("House").style_spec("b"),
+        Cell::new("Bhava").style_spec("b"),
         Cell::new("Dignity").style_spec("b"),
     ]));
 
     for planet in &planets {
-        if planet.name == "Sun" || planet.name == "Moon" || planet.name == "Rahu" || planet.name == "Ketu" {
+        if planet.name == "Sun"
+            || planet.name == "Moon"
+            || planet.name == "Rahu"
+            || planet.name == "Ketu"
+        {
             continue;
         }
         let (rasi, deg, min, sec) = rasi_details(planet.sidereal_long_deg);
-        let house = ((planet.sidereal_long_deg - asc_sid_deg) / 30.0).floor() as i32 % 12 + 1;
+        let house = whole_sign_house(planet.sidereal_long_deg, asc_sid_deg);
+        let bhava = chalit.planets[&planet.name].bhava;
         let dignity = calculate_dignity(planet)?;
         let dignity_status = if dignity.exalted {
             "Exalted"
@@ -117,41 +185,260 @@ fn main() -> Result<()> {
             Cell::new(&rasi),
             Cell::new(&format!("{}°{}'{:.1}\"", deg, min, sec)),
             Cell::new(&format!("H{}", house)),
+            Cell::new(&format!("B{}", bhava)),
             Cell::new(dignity_status),
         ]));
     }
     planet_table.printstd();
 
+    // Upagrahas and special lagnas, placed in the rasi and navamsa charts
+    println!("\n{}", "Special Points".bold());
+    let special_points =
+        compute_special_points(&birth_data, &planets, config.ayanamsa, sunrise_options)?;
+    let navamsa = calculate_navamsa_chart(&special_points.positions(), asc_sid_deg)?;
+    let mut points_table = Table::new();
+    points_table.set_titles(Row::new(vec![
+        Cell::new("Point").style_spec("b"),
+        Cell::new("Sign").style_spec("b"),
+        Cell::new("Position").style_spec("b"),
+        Cell::new("Navamsa").style_spec("b"),
+    ]));
+    for (name, longitude) in special_points.named() {
+        let (rasi, deg, min, sec) = rasi_details(longitude);
+        points_table.add_row(Row::new(vec![
+            Cell::new(name),
+            Cell::new(&rasi),
+            Cell::new(&format!("{}°{}'{:.1}\"", deg, min, sec)),
+            Cell::new(&navamsa.planets[name].rashi),
+        ]));
+    }
+    points_table.printstd();
+
+    // Arudha padas
+    println!("\n{}", "Arudha Padas".bold());
+    let rasi_chart = calculate_rasi_chart(&planets, asc_sid_deg)?;
+    let navamsa_chart = calculate_navamsa_chart(&planets, asc_sid_deg)?;
+    let d1_padas = calculate_arudha_padas(&rasi_chart, &compute_rasi(asc_sid_deg))?;
+    let d9_padas = calculate_arudha_padas(&navamsa_chart, &compute_navamsa(asc_sid_deg))?;
+    let mut arudha_table = Table::new();
+    arudha_table.set_titles(Row::new(vec![
+        Cell::new("Pada").style_spec("b"),
+        Cell::new("Rasi").style_spec("b"),
+        Cell::new("Navamsa").style_spec("b"),
+    ]));
+    for house in 1..=12u8 {
+        let label = match house {
+            1 => "A1 (AL)".to_string(),
+            12 => "A12 (UL)".to_string(),
+            _ => format!("A{}", house),
+        };
+        arudha_table.add_row(Row::new(vec![
+            Cell::new(&label),
+            Cell::new(d1_padas.pada(house)?),
+            Cell::new(d9_padas.pada(house)?),
+        ]));
+    }
+    arudha_table.printstd();
+
+    // Chara Karakas
+    println!("\n{}", "Chara Karakas".bold());
+    let karakas = calculate_chara_karakas(&planets, KarakaScheme::EightPlanets)?;
+    let mut karaka_table = Table::new();
+    karaka_table.set_titles(Row::new(vec![
+        Cell::new("Karaka").style_spec("b"),
+        Cell::new("Planet").style_spec("b"),
+        Cell::new("Degree").style_spec("b"),
+    ]));
+    for karaka in &karakas.karakas {
+        karaka_table.add_row(Row::new(vec![
+            Cell::new(karaka.karaka),
+            Cell::new(&karaka.planet),
+            Cell::new(&format!("{:.2}", karaka.degree)),
+        ]));
+    }
+    karaka_table.add_row(Row::new(vec![
+        Cell::new("Karakamsa"),
+        Cell::new(&karakas.karakamsa),
+        Cell::new(""),
+    ]));
+    karaka_table.printstd();
+
     // Panchanga
     println!("\n{}", "Panchanga (Five Limbs)".bold());
-    let panchanga = compute_panchanga(jd);
+    let panchanga = compute_panchanga_at(
+        jd,
+        birth_data.longitude,
+        birth_data.latitude,
+        config.ayanamsa,
+        sunrise_options,
+    )?;
+    let ends = |element: PanchangaElement| -> Result<String> {
+        let end = element_end(element, jd, config.ayanamsa)?;
+        Ok(format!(
+            "until {}",
+            jd_to_datetime(end, &Kolkata)?.format("%d %b %H:%M")
+        ))
+    };
     let mut panchanga_table = Table::new();
     panchanga_table.set_format(*format::consts::FORMAT_BOX_CHARS);
     panchanga_table.add_row(Row::new(vec![
         Cell::new("Tithi"),
-        Cell::new(&format!("{} {}", panchanga.tithi_number, panchanga.paksha)),
+        Cell::new(&format!(
+            "{} {} ({})",
+            panchanga.paksha,
+            tithi_name(panchanga.tithi_number),
+            panchanga.tithi_number
+        )),
+        Cell::new(&ends(PanchangaElement::Tithi)?),
     ]));
     panchanga_table.add_row(Row::new(vec![
         Cell::new("Vara"),
         Cell::new(&panchanga.weekday),
+        Cell::new(""),
     ]));
     panchanga_table.add_row(Row::new(vec![
         Cell::new("Nakshatra"),
-        Cell::new(&format!("{} ({})", panchanga.nakshatra_name, panchanga.nakshatra_index)),
+        Cell::new(&format!(
+            "{} ({})",
+            panchanga.nakshatra_name, panchanga.nakshatra_index
+        )),
+        Cell::new(&ends(PanchangaElement::Nakshatra)?),
     ]));
     panchanga_table.add_row(Row::new(vec![
         Cell::new("Yoga"),
-        Cell::new(&format!("{} ({})", panchanga.yoga_name, panchanga.yoga_index)),
+        Cell::new(&format!(
+            "{} ({})",
+            panchanga.yoga_name, panchanga.yoga_index
+        )),
+        Cell::new(&ends(PanchangaElement::Yoga)?),
     ]));
     panchanga_table.add_row(Row::new(vec![
         Cell::new("Karana"),
-        Cell::new(&format!("{} ({})", panchanga.karana_name, panchanga.karana_index)),
+        Cell::new(&format!(
+            "{} ({})",
+            panchanga.karana_name, panchanga.karana_index
+        )),
+        Cell::new(&ends(PanchangaElement::Karana)?),
+    ]));
+    let lunar_date = compute_lunar_date(jd, config.ayanamsa)?;
+    panchanga_table.add_row(Row::new(vec![
+        Cell::new("Masa"),
+        Cell::new(&format!(
+            "{}{} (amanta), {} (purnimanta)",
+            if lunar_date.adhika { "Adhika " } else { "" },
+            lunar_date.amanta_masa,
+            lunar_date.purnimanta_masa
+        )),
+        Cell::new(&match lunar_date.kshaya {
+            Some(kshaya) => format!("{} kshaya", kshaya),
+            None => String::new(),
+        }),
+    ]));
+    panchanga_table.add_row(Row::new(vec![
+        Cell::new("Samvat"),
+        Cell::new(&format!(
+            "Vikram {}, Shaka {}",
+            lunar_date.vikram_samvat, lunar_date.shaka_samvat
+        )),
+        Cell::new(lunar_date.samvatsara),
+    ]));
+    panchanga_table.add_row(Row::new(vec![
+        Cell::new("Ritu / Ayana"),
+        Cell::new(&format!("{} / {}", lunar_date.ritu, lunar_date.ayana)),
+        Cell::new(""),
     ]));
     panchanga_table.printstd();
 
+    // Daily Panchang of the birth date
+    println!("\n{}", "Daily Panchang".bold());
+    let birth_date = birth_data.datetime.with_timezone(&Kolkata).date_naive();
+    let days = daily_panchang(
+        birth_date,
+        birth_date,
+        birth_data.longitude,
+        birth_data.latitude,
+        &Kolkata,
+        config.ayanamsa,
+        sunrise_options,
+    )?;
+    let local = |event_jd: f64| -> Result<String> {
+        Ok(jd_to_datetime(event_jd, &Kolkata)?
+            .format("%H:%M")
+            .to_string())
+    };
+    let mut daily_table = Table::new();
+    daily_table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    for day in days.iter().filter_map(PanchangDay::panchang) {
+        daily_table.add_row(Row::new(vec![
+            Cell::new("Vara"),
+            Cell::new(&format!("{} ({})", day.vara, day.vara_lord)),
+        ]));
+        daily_table.add_row(Row::new(vec![
+            Cell::new("Moonrise"),
+            Cell::new(&match day.moonrise {
+                Some(moonrise) => local(moonrise)?,
+                None => "none".to_string(),
+            }),
+        ]));
+        daily_table.add_row(Row::new(vec![
+            Cell::new("Sun / Moon"),
+            Cell::new(&format!("{} / {}", day.sun_rashi, day.moon_rashi)),
+        ]));
+        for period in &day.periods {
+            daily_table.add_row(Row::new(vec![
+                Cell::new(period.name),
+                Cell::new(&format!(
+                    "{} - {} ({})",
+                    local(period.start)?,
+                    local(period.end)?,
+                    if period.auspicious {
+                        "auspicious"
+                    } else {
+                        "inauspicious"
+                    }
+                )),
+            ]));
+        }
+    }
+    // the hora and choghadiya of birth, from the Hindu day it falls in
+    for date in [birth_date.pred_opt().unwrap_or(birth_date), birth_date] {
+        let horas = compute_horas(
+            date,
+            birth_data.longitude,
+            birth_data.latitude,
+            &Kolkata,
+            sunrise_options,
+        )?;
+        let choghadiyas = compute_choghadiyas(
+            date,
+            birth_data.longitude,
+            birth_data.latitude,
+            &Kolkata,
+            sunrise_options,
+        )?;
+        for (label, periods) in [
+            ("Hora at birth", horas),
+            ("Choghadiya at birth", choghadiyas),
+        ] {
+            if let Some(period) = periods.iter().find(|p| p.contains(jd)) {
+                daily_table.add_row(Row::new(vec![
+                    Cell::new(label),
+                    Cell::new(&format!(
+                        "{} ({} - {}, {})",
+                        period.name,
+                        local(period.start)?,
+                        local(period.end)?,
+                        if period.auspicious { "good" } else { "bad" }
+                    )),
+                ]));
+            }
+        }
+    }
+    daily_table.printstd();
+
     // House Details
     println!("\n{}", "House Details".bold());
-    let houses = compute_whole_sign_houses(asc_sid_deg);
+    let houses = compute_house_cusps(&birth_data, HouseSystem::WholeSign, config.ayanamsa)?;
     let mut house_table = Table::new();
     house_table.set_titles(Row::new(vec![
         Cell::new("House").style_spec("b"),
@@ -163,14 +450,11 @@ fn main() -> Result<()> {
     for (i, &cusp) in houses.iter().enumerate() {
         let (rasi, deg, min, sec) = rasi_details(cusp);
         let house_num = i + 1;
-        
+
         // Get planets in this house
         let house_planets: Vec<String> = planets
             .iter()
-            .filter(|p| {
-                let planet_house = ((p.sidereal_long_deg - asc_sid_deg) / 30.0).floor() as usize % 12 + 1;
-                planet_house == house_num
-            })
+            .filter(|p| whole_sign_house(p.sidereal_long_deg, asc_sid_deg) as usize == house_num)
             .map(|p| p.name.clone())
             .collect();
 
@@ -219,15 +503,15 @@ fn main() -> Result<()> {
             dasha_table.set_format(*format::consts::FORMAT_BOX_CHARS);
             dasha_table.add_row(Row::new(vec![
                 Cell::new("Maha Dasha"),
-                Cell::new(&format!("{} ({:.2} years)", dashas.maha_dasha.planet, dashas.maha_dasha.years)),
+                Cell::new(&format_dasha(&dashas.maha_dasha)),
             ]));
             dasha_table.add_row(Row::new(vec![
                 Cell::new("Antara Dasha"),
-                Cell::new(&format!("{} ({:.2} years)", dashas.antara_dasha.planet, dashas.antara_dasha.years)),
+                Cell::new(&format_dasha(&dashas.antara_dasha)),
             ]));
             dasha_table.add_row(Row::new(vec![
                 Cell::new("Pratyantara"),
-                Cell::new(&format!("{} ({:.2} years)", dashas.pratyantara_dasha.planet, dashas.pratyantara_dasha.years)),
+                Cell::new(&format_dasha(&dashas.pratyantara_dasha)),
             ]));
             dasha_table.printstd();
         }
@@ -254,4 +538,19 @@ fn main() -> Result<()> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+fn format_dasha(period: &DashaPeriod) -> String {
+    match (
+        period.start_datetime(&Kolkata),
+        period.end_datetime(&Kolkata),
+    ) {
+        (Ok(start), Ok(end)) => format!(
+            "{} ({:.2} years, {} to {})",
+            period.planet,
+            period.years,
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        ),
+        _ => format!("{} ({:.2} years)", period.planet, period.years),
+    }
+}
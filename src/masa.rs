@@ -0,0 +1,242 @@
+//! Lunar month (masa) and the naming of the year.
+//!
+//! An amanta month runs from one new moon to the next and is named from the
+//! sidereal sign of the Sun at the new moon that opens it: the Sun in Mina
+//! opens Chaitra, in Mesha Vaishakha, and so on. A month within which the
+//! Sun enters no sign is adhika (intercalary) and shares the name of the
+//! month after it. A month within which the Sun enters two signs takes the
+//! first of the two names, and the second is kshaya (lost). Purnimanta
+//! months run from full moon to full moon, so their dark fortnight takes the
+//! name of the next amanta month; adhika months keep the amanta span in
+//! both conventions.
+//!
+//! The year begins with Chaitra, or with the adhika Chaitra in a year that
+//! has one. The Vikram and Shaka samvats count years
+//! from 57 BCE and 78 CE. The samvatsara is the name of the year in the
+//! 60-year Jovian cycle as counted in the south, where it runs with the
+//! luni-solar year. Ritus pair the months from Chaitra; the ayana follows
+//! the Sun's sidereal sign, Uttarayana running from Makara to Mithuna.
+//!
+//! All `jd` arguments are JD(UT).
+
+use serde::Serialize;
+
+use crate::transitions::{next_new_moon, previous_new_moon};
+use crate::{calendar_date, compute_tithi, sun_moon_sidereal, Ayanamsa, Calendar, Result};
+
+/// Names of the lunar months, Chaitra first.
+pub const MASA_NAMES: [&str; 12] = [
+    "Chaitra",
+    "Vaishakha",
+    "Jyeshtha",
+    "Ashadha",
+    "Shravana",
+    "Bhadrapada",
+    "Ashvina",
+    "Kartika",
+    "Margashirsha",
+    "Pausha",
+    "Magha",
+    "Phalguna",
+];
+
+/// Names of the six seasons, Vasanta first.
+pub const RITU_NAMES: [&str; 6] = [
+    "Vasanta", "Grishma", "Varsha", "Sharad", "Hemanta", "Shishira",
+];
+
+/// Names of the years of the 60-year cycle, Prabhava first.
+pub const SAMVATSARA_NAMES: [&str; 60] = [
+    "Prabhava",
+    "Vibhava",
+    "Shukla",
+    "Pramoda",
+    "Prajapati",
+    "Angirasa",
+    "Shrimukha",
+    "Bhava",
+    "Yuva",
+    "Dhatri",
+    "Ishvara",
+    "Bahudhanya",
+    "Pramathi",
+    "Vikrama",
+    "Vrisha",
+    "Chitrabhanu",
+    "Subhanu",
+    "Tarana",
+    "Parthiva",
+    "Vyaya",
+    "Sarvajit",
+    "Sarvadhari",
+    "Virodhi",
+    "Vikriti",
+    "Khara",
+    "Nandana",
+    "Vijaya",
+    "Jaya",
+    "Manmatha",
+    "Durmukhi",
+    "Hevilambi",
+    "Vilambi",
+    "Vikari",
+    "Sharvari",
+    "Plava",
+    "Shubhakrit",
+    "Shobhakrit",
+    "Krodhi",
+    "Vishvavasu",
+    "Parabhava",
+    "Plavanga",
+    "Kilaka",
+    "Saumya",
+    "Sadharana",
+    "Virodhikrit",
+    "Paridhavi",
+    "Pramadicha",
+    "Ananda",
+    "Rakshasa",
+    "Nala",
+    "Pingala",
+    "Kalayukti",
+    "Siddharthi",
+    "Raudra",
+    "Durmati",
+    "Dundubhi",
+    "Rudhirodgari",
+    "Raktakshi",
+    "Krodhana",
+    "Akshaya",
+];
+
+/// Lunar month and year in force at a moment.
+#[derive(Debug, Clone, Serialize)]
+pub struct LunarDate {
+    /// Amanta month, 0 = Chaitra.
+    pub amanta_index: usize,
+    pub amanta_masa: &'static str,
+    /// Purnimanta month, 0 = Chaitra.
+    pub purnimanta_index: usize,
+    pub purnimanta_masa: &'static str,
+    /// Whether the month is intercalary.
+    pub adhika: bool,
+    /// Month lost after this one, when the Sun enters two signs within it.
+    pub kshaya: Option<&'static str>,
+    /// JD(UT) of the new moon that opens the amanta month.
+    pub month_start: f64,
+    /// JD(UT) of the new moon that closes it.
+    pub month_end: f64,
+    pub vikram_samvat: i32,
+    pub shaka_samvat: i32,
+    /// Year of the 60-year cycle, 1 = Prabhava.
+    pub samvatsara_index: u8,
+    pub samvatsara: &'static str,
+    pub ritu: &'static str,
+    pub ayana: &'static str,
+}
+
+/// Lunar month and year at `jd_ut`, with the Sun's sign read in the given
+/// ayanamsa.
+pub fn compute_lunar_date(jd_ut: f64, ayanamsa: Ayanamsa) -> Result<LunarDate> {
+    let month_start = previous_new_moon(jd_ut, ayanamsa)?;
+    let month_end = next_new_moon(jd_ut, ayanamsa)?;
+    let sun_sign = |jd: f64| (sun_moon_sidereal(jd, ayanamsa).0 / 30.0).floor() as usize % 12;
+    let opening_sign = sun_sign(month_start);
+    let closing_sign = sun_sign(month_end);
+    let sankrantis = (closing_sign + 12 - opening_sign) % 12;
+
+    let amanta_index = (opening_sign + 1) % 12;
+    let adhika = sankrantis == 0;
+    let kshaya = (sankrantis == 2).then(|| MASA_NAMES[(opening_sign + 2) % 12]);
+    let (tithi, _) = compute_tithi(jd_ut);
+    let purnimanta_index = if tithi > 15 && !adhika {
+        (closing_sign + 1) % 12
+    } else {
+        amanta_index
+    };
+
+    // back to the new moon that opened the year's Chaitra, in March or April
+    let mut year_start = month_start;
+    while sun_sign(year_start) != 11 {
+        year_start = previous_new_moon(year_start - 1.0, ayanamsa)?;
+    }
+    let before = previous_new_moon(year_start - 1.0, ayanamsa)?;
+    if sun_sign(before) == 11 {
+        year_start = before;
+    }
+    let year = calendar_date(year_start, Calendar::Gregorian)?.year;
+    let shaka_samvat = year - 78;
+    let samvatsara_index = ((shaka_samvat + 11).rem_euclid(60) + 1) as u8;
+
+    let sun_now = sun_sign(jd_ut);
+    Ok(LunarDate {
+        amanta_index,
+        amanta_masa: MASA_NAMES[amanta_index],
+        purnimanta_index,
+        purnimanta_masa: MASA_NAMES[purnimanta_index],
+        adhika,
+        kshaya,
+        month_start,
+        month_end,
+        vikram_samvat: year + 57,
+        shaka_samvat,
+        samvatsara_index,
+        samvatsara: samvatsara_name(samvatsara_index),
+        ritu: RITU_NAMES[amanta_index / 2],
+        ayana: if (9..12).contains(&sun_now) || sun_now < 3 {
+            "Uttarayana"
+        } else {
+            "Dakshinayana"
+        },
+    })
+}
+
+/// Name of year 1–60 of the Jovian cycle; "Unknown" outside that range.
+pub fn samvatsara_name(index: u8) -> &'static str {
+    match index {
+        1..=60 => SAMVATSARA_NAMES[index as usize - 1],
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lunar_date(jd_ut: f64) -> LunarDate {
+        compute_lunar_date(jd_ut, Ayanamsa::Lahiri).unwrap()
+    }
+
+    #[test]
+    fn shravana_2023_is_adhika() {
+        // 2023 August 1, in the adhika Shravana of 18 July to 16 August
+        let date = lunar_date(2460158.0);
+        assert_eq!((date.amanta_masa, date.adhika), ("Shravana", true));
+        assert_eq!(date.kshaya, None);
+        assert_eq!((date.vikram_samvat, date.shaka_samvat), (2080, 1945));
+    }
+
+    #[test]
+    fn year_turns_at_the_chaitra_new_moon() {
+        // the new moon of 2024 April 8, 18:21 UT, opened Chaitra
+        let before = lunar_date(2460408.5);
+        assert_eq!(before.amanta_masa, "Phalguna");
+        assert_eq!((before.vikram_samvat, before.shaka_samvat), (2080, 1945));
+        assert_eq!(
+            (before.samvatsara_index, before.samvatsara),
+            (37, "Shobhakrit")
+        );
+
+        let after = lunar_date(2460410.0);
+        assert_eq!(after.amanta_masa, "Chaitra");
+        assert_eq!((after.vikram_samvat, after.shaka_samvat), (2081, 1946));
+        assert_eq!((after.samvatsara_index, after.samvatsara), (38, "Krodhi"));
+    }
+
+    #[test]
+    fn samvatsara_of_1991_is_prajapati() {
+        let date = lunar_date(2448425.5);
+        assert_eq!((date.vikram_samvat, date.shaka_samvat), (2048, 1913));
+        assert_eq!(date.samvatsara, "Prajapati");
+    }
+}
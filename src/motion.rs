@@ -0,0 +1,185 @@
+//! Daily motion, retrogression and stations of the planets.
+//!
+//! Speeds are sidereal daily motions in longitude: the geocentric rate from
+//! the ephemeris less the rate of the ayanamsa. A planet counts as
+//! stationary while its speed is within a tenth of its mean geocentric
+//! motion; [`find_stations`] locates the instants where the speed is zero.
+//!
+//! All `jd` arguments, and the station times found, are JD(TT).
+
+use chrono::{DateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compute_geocentric_position, get_geocentric, jd_to_datetime, tt_to_ut, Ayanamsa, Result,
+    VedicError, RAD_TO_DEG,
+};
+
+/// Fraction of the mean daily motion below which a planet is stationary.
+const STATIONARY_FRACTION: f64 = 0.1;
+
+/// Scan step of [`find_stations`], days; shorter than any retrograde loop.
+const STATION_SCAN_STEP: f64 = 1.0;
+
+/// Mean geocentric daily motion in degrees, for planets that can station.
+fn mean_daily_motion(planet: &str) -> Option<f64> {
+    match planet {
+        // the inner planets keep pace with the Sun on average
+        "Mercury" | "Venus" => Some(0.9856),
+        "Mars" => Some(0.5240),
+        "Jupiter" => Some(0.0831),
+        "Saturn" => Some(0.0335),
+        "Uranus" => Some(0.0117),
+        "Neptune" => Some(0.0060),
+        _ => None,
+    }
+}
+
+/// Whether `planet` moving at `speed` deg/day is at or near a station. The
+/// Sun, Moon and nodes never are.
+pub fn is_stationary(planet: &str, speed: f64) -> bool {
+    mean_daily_motion(planet).is_some_and(|mean| speed.abs() < STATIONARY_FRACTION * mean)
+}
+
+/// Sidereal daily motion in longitude of the Sun, Moon or a planet, deg/day.
+pub fn sidereal_speed(planet: &str, jd: f64, ayanamsa: Ayanamsa) -> Result<f64> {
+    let [_, _, _, lon_rate, ..] = get_geocentric(planet, jd)?;
+    Ok(lon_rate * RAD_TO_DEG - ayanamsa.rate(jd))
+}
+
+/// Direction a planet turns at a station.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StationKind {
+    /// Direct motion stops and retrogression begins.
+    Retrograde,
+    /// Retrogression ends and direct motion resumes.
+    Direct,
+}
+
+/// A moment at which a planet's motion in longitude reverses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Station {
+    pub planet: String,
+    pub jd: f64,
+    pub kind: StationKind,
+    pub sidereal_long_deg: f64,
+}
+
+impl Station {
+    /// Moment of the station as a civil timestamp in `tz`.
+    pub fn datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(tt_to_ut(self.jd), tz)
+    }
+}
+
+/// Find every station of `planet` between `start_jd` and `end_jd`, in time
+/// order. The speed is scanned a day at a time and each sign change is
+/// bisected down to well under a second.
+pub fn find_stations(
+    planet: &str,
+    start_jd: f64,
+    end_jd: f64,
+    ayanamsa: Ayanamsa,
+) -> Result<Vec<Station>> {
+    if end_jd < start_jd {
+        return Err(VedicError::InvalidDateTime(format!(
+            "station search ends ({}) before it starts ({})",
+            end_jd, start_jd
+        )));
+    }
+
+    let mut stations = Vec::new();
+    let mut jd = start_jd;
+    let mut speed = sidereal_speed(planet, jd, ayanamsa)?;
+
+    while jd < end_jd {
+        let next_jd = (jd + STATION_SCAN_STEP).min(end_jd);
+        let next_speed = sidereal_speed(planet, next_jd, ayanamsa)?;
+
+        if speed.signum() != next_speed.signum() {
+            let (mut lo, mut hi) = (jd, next_jd);
+            for _ in 0..40 {
+                let mid = 0.5 * (lo + hi);
+                if sidereal_speed(planet, mid, ayanamsa)?.signum() == speed.signum() {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+
+            let station_jd = 0.5 * (lo + hi);
+            stations.push(Station {
+                planet: planet.to_string(),
+                jd: station_jd,
+                kind: if speed > 0.0 {
+                    StationKind::Retrograde
+                } else {
+                    StationKind::Direct
+                },
+                sidereal_long_deg: compute_geocentric_position(planet, station_jd, ayanamsa)?
+                    .sidereal_long_deg,
+            });
+        }
+
+        jd = next_jd;
+        speed = next_speed;
+    }
+
+    Ok(stations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// JD(UT) of a day of 2024 at the given hour.
+    fn jd_2024(day_of_year: u32, hours: f64) -> f64 {
+        2460310.5 + (day_of_year - 1) as f64 + hours / 24.0
+    }
+
+    #[test]
+    fn mercury_stations_of_april_2024() {
+        // published stations: retrograde 1 April 22:14 UT, direct 25 April 12:54 UT
+        let stations = find_stations("Mercury", 2460390.5, 2460440.5, Ayanamsa::Lahiri).unwrap();
+        let found: Vec<_> = stations
+            .iter()
+            .map(|station| (station.kind, tt_to_ut(station.jd)))
+            .collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, StationKind::Retrograde);
+        assert!(
+            (found[0].1 - jd_2024(92, 22.23)).abs() < 2.0 / 24.0,
+            "{}",
+            found[0].1
+        );
+        assert_eq!(found[1].0, StationKind::Direct);
+        assert!(
+            (found[1].1 - jd_2024(116, 12.9)).abs() < 2.0 / 24.0,
+            "{}",
+            found[1].1
+        );
+    }
+
+    #[test]
+    fn mars_station_of_december_2024() {
+        // published station retrograde: 6 December 23:33 UT
+        let stations = find_stations("Mars", 2460640.5, 2460660.5, Ayanamsa::Lahiri).unwrap();
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].kind, StationKind::Retrograde);
+        let jd = tt_to_ut(stations[0].jd);
+        assert!((jd - jd_2024(341, 23.55)).abs() < 2.0 / 24.0, "{}", jd);
+        assert!(is_stationary(
+            "Mars",
+            sidereal_speed("Mars", stations[0].jd, Ayanamsa::Lahiri).unwrap()
+        ));
+    }
+
+    #[test]
+    fn stationary_below_a_tenth_of_the_mean_motion() {
+        assert!(is_stationary("Mars", 0.05));
+        assert!(!is_stationary("Mars", -0.06));
+        assert!(is_stationary("Saturn", -0.003));
+        assert!(!is_stationary("Moon", 0.0));
+        assert!(!is_stationary("Rahu", 0.0));
+    }
+}
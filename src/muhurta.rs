@@ -0,0 +1,327 @@
+//! Auspicious and inauspicious periods of the day.
+//!
+//! Rahu Kaal, Yamaganda and Gulika Kaal are each one of the eight equal
+//! parts of the daytime, from sunrise to sunset, chosen by the weekday.
+//! Abhijit Muhurta is the eighth of the fifteen muhurtas of the daytime,
+//! the one that holds local noon. Durmuhurta is one or two muhurtas of the
+//! day (on Tuesday one of the night) chosen by the weekday, and Brahma
+//! Muhurta the last but one muhurta of the night before sunrise.
+//! Varjyam and Amrit Kaal last four of the sixty ghatis of a nakshatra,
+//! starting a number of ghatis into it that depends on the nakshatra.
+//!
+//! All `jd` arguments and results are JD(UT); weekdays count from 0 =
+//! Sunday, as in [`crate::weekday_index`].
+
+use chrono::{DateTime, NaiveDate, TimeZone};
+use serde::Serialize;
+
+use crate::calendar::local_midnight;
+use crate::rise_set::{sunrise, sunset, RiseSetOptions};
+use crate::transitions::{element_spans, ElementSpan, PanchangaElement};
+use crate::{jd_to_datetime, weekday_index, Ayanamsa, Result, VedicError};
+
+/// Part of the daytime (1–8) held by Rahu Kaal, Sunday first.
+const RAHU_KAAL_PART: [u8; 7] = [8, 2, 7, 5, 6, 4, 3];
+
+/// Part of the daytime (1–8) held by Yamaganda, Sunday first.
+const YAMAGANDA_PART: [u8; 7] = [5, 4, 3, 2, 1, 7, 6];
+
+/// Part of the daytime (1–8) held by Gulika Kaal, Sunday first.
+const GULIKA_KAAL_PART: [u8; 7] = [7, 6, 5, 4, 3, 2, 1];
+
+/// Muhurtas (1–15) of the day and of the night that are Durmuhurta,
+/// Sunday first.
+const DURMUHURTA: [(&[u8], &[u8]); 7] = [
+    (&[14], &[]),
+    (&[9, 12], &[]),
+    (&[4], &[7]),
+    (&[8], &[]),
+    (&[6, 12], &[]),
+    (&[4, 9], &[]),
+    (&[1, 2], &[]),
+];
+
+/// Ghatis into each nakshatra at which Varjyam begins, Ashwini first.
+const VARJYAM_GHATI: [u8; 27] = [
+    50, 24, 30, 40, 14, 21, 30, 20, 32, 30, 20, 18, 21, 20, 14, 14, 10, 14, 20, 24, 20, 10, 10, 18,
+    16, 24, 30,
+];
+
+/// Ghatis into each nakshatra at which Amrit Kaal begins, Ashwini first.
+const AMRIT_KAAL_GHATI: [u8; 27] = [
+    42, 48, 54, 52, 38, 35, 54, 44, 56, 54, 44, 42, 45, 44, 38, 38, 34, 38, 44, 48, 44, 34, 34, 42,
+    40, 48, 54,
+];
+
+/// A named stretch of time and whether it favours new undertakings.
+#[derive(Debug, Clone, Serialize)]
+pub struct Period {
+    pub name: &'static str,
+    /// JD(UT) at which the period begins.
+    pub start: f64,
+    /// JD(UT) at which the period ends.
+    pub end: f64,
+    pub auspicious: bool,
+}
+
+impl Period {
+    /// Whether `jd_ut` falls within the period.
+    pub fn contains(&self, jd_ut: f64) -> bool {
+        self.start <= jd_ut && jd_ut < self.end
+    }
+
+    /// Start of the period as a timestamp in `tz`.
+    pub fn start_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.start, tz)
+    }
+
+    /// End of the period as a timestamp in `tz`.
+    pub fn end_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.end, tz)
+    }
+}
+
+/// Rahu Kaal of the day from `sunrise_jd` to `sunset_jd`.
+pub fn rahu_kaal(sunrise_jd: f64, sunset_jd: f64, weekday: usize) -> Period {
+    eighth(
+        "Rahu Kaal",
+        sunrise_jd,
+        sunset_jd,
+        RAHU_KAAL_PART[weekday % 7],
+    )
+}
+
+/// Yamaganda of the day from `sunrise_jd` to `sunset_jd`.
+pub fn yamaganda(sunrise_jd: f64, sunset_jd: f64, weekday: usize) -> Period {
+    eighth(
+        "Yamaganda",
+        sunrise_jd,
+        sunset_jd,
+        YAMAGANDA_PART[weekday % 7],
+    )
+}
+
+/// Gulika Kaal of the day from `sunrise_jd` to `sunset_jd`.
+pub fn gulika_kaal(sunrise_jd: f64, sunset_jd: f64, weekday: usize) -> Period {
+    eighth(
+        "Gulika Kaal",
+        sunrise_jd,
+        sunset_jd,
+        GULIKA_KAAL_PART[weekday % 7],
+    )
+}
+
+/// Abhijit Muhurta of the day from `sunrise_jd` to `sunset_jd`.
+pub fn abhijit_muhurta(sunrise_jd: f64, sunset_jd: f64) -> Period {
+    let muhurta = (sunset_jd - sunrise_jd) / 15.0;
+    Period {
+        name: "Abhijit Muhurta",
+        start: sunrise_jd + 7.0 * muhurta,
+        end: sunrise_jd + 8.0 * muhurta,
+        auspicious: true,
+    }
+}
+
+/// Durmuhurtas of the day from `sunrise_jd` to `sunset_jd` and the night
+/// on to `next_sunrise_jd`.
+pub fn durmuhurtas(
+    sunrise_jd: f64,
+    sunset_jd: f64,
+    next_sunrise_jd: f64,
+    weekday: usize,
+) -> Vec<Period> {
+    let (day, night) = DURMUHURTA[weekday % 7];
+    let day_muhurta = (sunset_jd - sunrise_jd) / 15.0;
+    let night_muhurta = (next_sunrise_jd - sunset_jd) / 15.0;
+    let by_day = day.iter().map(|&n| (sunrise_jd, day_muhurta, n));
+    let by_night = night.iter().map(|&n| (sunset_jd, night_muhurta, n));
+    by_day
+        .chain(by_night)
+        .map(|(from, muhurta, n)| Period {
+            name: "Durmuhurta",
+            start: from + (n - 1) as f64 * muhurta,
+            end: from + n as f64 * muhurta,
+            auspicious: false,
+        })
+        .collect()
+}
+
+/// Brahma Muhurta of the night from `sunset_jd` to `sunrise_jd`.
+pub fn brahma_muhurta(sunset_jd: f64, sunrise_jd: f64) -> Period {
+    let muhurta = (sunrise_jd - sunset_jd) / 15.0;
+    Period {
+        name: "Brahma Muhurta",
+        start: sunrise_jd - 2.0 * muhurta,
+        end: sunrise_jd - muhurta,
+        auspicious: true,
+    }
+}
+
+/// Varjyam of a nakshatra as found by [`crate::element_spans`].
+pub fn varjyam(nakshatra: &ElementSpan) -> Result<Period> {
+    nakshatra_portion("Varjyam", &VARJYAM_GHATI, nakshatra, false)
+}
+
+/// Amrit Kaal of a nakshatra as found by [`crate::element_spans`].
+pub fn amrit_kaal(nakshatra: &ElementSpan) -> Result<Period> {
+    nakshatra_portion("Amrit Kaal", &AMRIT_KAAL_GHATI, nakshatra, true)
+}
+
+/// Every period of the Hindu day from `sunrise_jd` to `next_sunrise_jd`,
+/// in order of their start: those of [`day_periods`], the Durmuhurtas, the
+/// Brahma Muhurta before the sunrise, and the Varjyam and Amrit Kaal of
+/// `nakshatras` that fall within the day.
+pub fn muhurta_periods(
+    previous_sunset_jd: f64,
+    sunrise_jd: f64,
+    sunset_jd: f64,
+    next_sunrise_jd: f64,
+    weekday: usize,
+    nakshatras: &[ElementSpan],
+) -> Result<Vec<Period>> {
+    let mut periods = day_periods(sunrise_jd, sunset_jd, weekday);
+    periods.extend(durmuhurtas(sunrise_jd, sunset_jd, next_sunrise_jd, weekday));
+    periods.push(brahma_muhurta(previous_sunset_jd, sunrise_jd));
+    for nakshatra in nakshatras {
+        for period in [varjyam(nakshatra)?, amrit_kaal(nakshatra)?] {
+            if period.start < next_sunrise_jd && period.end > sunrise_jd {
+                periods.push(period);
+            }
+        }
+    }
+    periods.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(periods)
+}
+
+/// Rahu Kaal, Yamaganda, Gulika Kaal, Durmuhurta, Varjyam, Amrit Kaal,
+/// Abhijit Muhurta and Brahma Muhurta of the Hindu day that begins at the
+/// sunrise of civil `date` in `tz`, at the given place, sunrise and sunset
+/// being defined by `options`.
+pub fn compute_muhurtas<Tz: TimeZone>(
+    date: NaiveDate,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    tz: &Tz,
+    ayanamsa: Ayanamsa,
+    options: RiseSetOptions,
+) -> Result<Vec<Period>> {
+    let rise = sunrise(
+        local_midnight(date, tz)?,
+        longitude_deg,
+        latitude_deg,
+        options,
+    )?;
+    let set = sunset(rise, longitude_deg, latitude_deg, options)?;
+    let next_rise = sunrise(rise + 0.01, longitude_deg, latitude_deg, options)?;
+    let previous_set = sunset(rise - 1.0, longitude_deg, latitude_deg, options)?;
+    let nakshatras = element_spans(PanchangaElement::Nakshatra, rise, next_rise, ayanamsa)?;
+    muhurta_periods(
+        previous_set,
+        rise,
+        set,
+        next_rise,
+        weekday_index(rise + longitude_deg / 360.0),
+        &nakshatras,
+    )
+}
+
+/// Rahu Kaal, Yamaganda, Gulika Kaal and Abhijit Muhurta of one day, in
+/// order of their start.
+pub fn day_periods(sunrise_jd: f64, sunset_jd: f64, weekday: usize) -> Vec<Period> {
+    let mut periods = vec![
+        rahu_kaal(sunrise_jd, sunset_jd, weekday),
+        yamaganda(sunrise_jd, sunset_jd, weekday),
+        gulika_kaal(sunrise_jd, sunset_jd, weekday),
+        abhijit_muhurta(sunrise_jd, sunset_jd),
+    ];
+    periods.sort_by(|a, b| a.start.total_cmp(&b.start));
+    periods
+}
+
+/// Inauspicious `part` (1–8) of the daytime.
+fn eighth(name: &'static str, sunrise_jd: f64, sunset_jd: f64, part: u8) -> Period {
+    let length = (sunset_jd - sunrise_jd) / 8.0;
+    let start = sunrise_jd + (part - 1) as f64 * length;
+    Period {
+        name,
+        start,
+        end: start + length,
+        auspicious: false,
+    }
+}
+
+/// Four ghatis of `nakshatra`, starting the tabulated number of ghatis in.
+fn nakshatra_portion(
+    name: &'static str,
+    ghatis: &[u8; 27],
+    nakshatra: &ElementSpan,
+    auspicious: bool,
+) -> Result<Period> {
+    if nakshatra.element != PanchangaElement::Nakshatra || !(1..=27).contains(&nakshatra.index) {
+        return Err(VedicError::CalculationError(format!(
+            "{} needs a nakshatra, not {:?} {}",
+            name, nakshatra.element, nakshatra.index
+        )));
+    }
+    let ghati = (nakshatra.end - nakshatra.start) / 60.0;
+    let start = nakshatra.start + ghatis[nakshatra.index as usize - 1] as f64 * ghati;
+    Ok(Period {
+        name,
+        start,
+        end: start + 4.0 * ghati,
+        auspicious,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rahu_kaal_in_delhi_matches_drik_panchang() {
+        // Monday 2024 January 1, 08:31 to 09:49 IST
+        let ist = chrono::FixedOffset::east_opt(19800).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let periods = compute_muhurtas(
+            date,
+            77.209,
+            28.6139,
+            &ist,
+            Ayanamsa::Lahiri,
+            RiseSetOptions::ALMANAC,
+        )
+        .unwrap();
+        let rahu = periods.iter().find(|p| p.name == "Rahu Kaal").unwrap();
+        let clock = |jd: f64| {
+            let time = jd_to_datetime(jd, &ist).unwrap();
+            time.format("%H:%M").to_string()
+        };
+        assert_eq!(clock(rahu.start), "08:31");
+        assert_eq!(clock(rahu.end), "09:49");
+    }
+
+    #[test]
+    fn rahu_kaal_in_delhi_on_a_tuesday() {
+        // Tuesday 2024 January 2: sunrise 07:14 and sunset 17:36 IST, and
+        // Rahu Kaal the seventh eighth of the day, 15:00 to 16:18 IST
+        let ist = chrono::FixedOffset::east_opt(19800).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let periods = compute_muhurtas(
+            date,
+            77.209,
+            28.6139,
+            &ist,
+            Ayanamsa::Lahiri,
+            RiseSetOptions::ALMANAC,
+        )
+        .unwrap();
+        let rahu = periods.iter().find(|p| p.name == "Rahu Kaal").unwrap();
+        let clock = |jd: f64| {
+            let time = jd_to_datetime(jd, &ist).unwrap();
+            time.format("%H:%M").to_string()
+        };
+        assert_eq!(clock(rahu.start), "15:00");
+        assert_eq!(clock(rahu.end), "16:18");
+        assert!(!rahu.auspicious);
+    }
+}
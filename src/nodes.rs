@@ -0,0 +1,119 @@
+//! Lunar nodes: Rahu (ascending) and Ketu (descending).
+//!
+//! Rahu is either the mean node, which moves uniformly backwards along the
+//! ecliptic, or the true node, the ascending node of the Moon's osculating
+//! orbit. Ketu is always exactly opposite Rahu. The nodes are points on the
+//! ecliptic, so both are reported retrograde with zero latitude and distance.
+//!
+//! All `jd` arguments are JD(TT).
+
+use serde::{Deserialize, Serialize};
+
+use crate::elp::mean_lunar_node;
+use crate::{get_moon, normalize_degrees, Ayanamsa, PlanetPosition, J2000, RAD_TO_DEG};
+
+/// Which node of the Moon's orbit stands for Rahu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NodeType {
+    /// Mean node of the lunar orbit (Meeus, eq. 47.7).
+    #[default]
+    Mean,
+    /// Osculating node of the ELP2000-82 orbit at the given instant.
+    True,
+}
+
+/// Tropical longitude of Rahu in degrees, ecliptic and equinox of date.
+pub fn rahu_longitude(jd: f64, node_type: NodeType) -> f64 {
+    match node_type {
+        NodeType::Mean => normalize_degrees(mean_lunar_node((jd - J2000) / 36525.0)),
+        NodeType::True => {
+            let [.., q, p] = get_moon(jd);
+            normalize_degrees(p.atan2(q) * RAD_TO_DEG)
+        }
+    }
+}
+
+/// Sidereal positions of Rahu and Ketu, Ketu being Rahu + 180°.
+pub fn compute_lunar_nodes(
+    jd: f64,
+    node_type: NodeType,
+    ayanamsa: Ayanamsa,
+) -> (PlanetPosition, PlanetPosition) {
+    let rahu = ayanamsa.to_sidereal(rahu_longitude(jd, node_type), jd);
+    let mut motion = rahu_longitude(jd + 0.5, node_type) - rahu_longitude(jd - 0.5, node_type);
+    if motion > 180.0 {
+        motion -= 360.0;
+    } else if motion < -180.0 {
+        motion += 360.0;
+    }
+    let speed = motion - ayanamsa.rate(jd);
+
+    let node = |name: &str, longitude: f64| PlanetPosition {
+        name: name.to_string(),
+        sidereal_long_deg: longitude,
+        latitude_deg: 0.0,
+        distance_au: 0.0,
+        speed_deg_per_day: speed,
+        retrograde: true,
+        stationary: false,
+    };
+
+    (
+        node("Rahu", rahu),
+        node("Ketu", normalize_degrees(rahu + 180.0)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Signed difference `a − b` in degrees, in (−180, 180].
+    fn separation(a: f64, b: f64) -> f64 {
+        180.0 - normalize_degrees(180.0 - (a - b))
+    }
+
+    #[test]
+    fn true_node_matches_swiss_ephemeris_at_j2000() {
+        // mean node 125°02′40″, true node 123°57′
+        let mean = rahu_longitude(J2000, NodeType::Mean);
+        assert!(
+            (mean - (125.0 + 2.0 / 60.0 + 40.0 / 3600.0)).abs() < 1e-3,
+            "{}",
+            mean
+        );
+        let true_node = rahu_longitude(J2000, NodeType::True);
+        assert!(
+            (true_node - (123.0 + 57.0 / 60.0)).abs() < 1.0 / 60.0,
+            "{}",
+            true_node
+        );
+    }
+
+    #[test]
+    fn true_node_swings_about_the_mean_node() {
+        let offsets: Vec<f64> = (0..73)
+            .map(|n| J2000 + 5.0 * n as f64)
+            .map(|jd| {
+                separation(
+                    rahu_longitude(jd, NodeType::True),
+                    rahu_longitude(jd, NodeType::Mean),
+                )
+            })
+            .collect();
+        let largest = offsets.iter().fold(0.0f64, |a, &b| a.max(b));
+        let smallest = offsets.iter().fold(0.0f64, |a, &b| a.min(b));
+        assert!(largest > 1.0 && largest < 2.0, "{}", largest);
+        assert!(smallest < -1.0 && smallest > -2.0, "{}", smallest);
+    }
+
+    #[test]
+    fn ketu_is_opposite_rahu() {
+        for node_type in [NodeType::Mean, NodeType::True] {
+            let (rahu, ketu) = compute_lunar_nodes(J2000 + 1234.5, node_type, Ayanamsa::Lahiri);
+            let gap = normalize_degrees(ketu.sidereal_long_deg - rahu.sidereal_long_deg);
+            assert!((gap - 180.0).abs() < 1e-9);
+            assert_eq!(rahu.speed_deg_per_day, ketu.speed_deg_per_day);
+        }
+    }
+}
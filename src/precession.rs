@@ -0,0 +1,164 @@
+//! Precession of the ecliptic and equinox.
+//!
+//! The mean ecliptic and equinox of date are reached from those of J2000 by
+//! three rotations through the ecliptic precession angles: π, the inclination
+//! of the ecliptic of date on that of J2000; Π, the longitude of its
+//! ascending node; and p, the general precession in longitude. Two models are
+//! offered: IAU 1976 (Lieske et al., 1977, as given by Meeus, eq. 21.5) and
+//! IAU 2006 (Capitaine et al., 2003, P03).
+//!
+//! All `jd` arguments are JD(TT).
+
+use serde::{Deserialize, Serialize};
+
+use crate::{normalize_degrees, DEG_TO_RAD, J2000, RAD_TO_DEG};
+
+const DAYS_PER_CENTURY: f64 = 36525.0;
+
+/// Radians per arcsecond.
+const ARCSEC: f64 = DEG_TO_RAD / 3600.0;
+
+/// A 3×3 rotation matrix acting on column vectors.
+pub type Matrix = [[f64; 3]; 3];
+
+/// Precession theory used to relate the J2000 ecliptic to the ecliptic of date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PrecessionModel {
+    /// Lieske et al. (1977), adopted by the IAU in 1976.
+    Iau1976,
+    /// Capitaine et al. (2003), adopted by the IAU in 2006.
+    #[default]
+    Iau2006,
+}
+
+/// Ecliptic precession angles (π, Π, p) at `jd`, in radians.
+pub fn ecliptic_precession_angles(jd: f64, model: PrecessionModel) -> (f64, f64, f64) {
+    let t = (jd - J2000) / DAYS_PER_CENTURY;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    match model {
+        PrecessionModel::Iau1976 => (
+            (47.0029 * t - 0.03302 * t2 + 0.000060 * t3) * ARCSEC,
+            174.876384 * DEG_TO_RAD - (869.8089 * t - 0.03536 * t2) * ARCSEC,
+            (5029.0966 * t + 1.11113 * t2 - 0.000006 * t3) * ARCSEC,
+        ),
+        PrecessionModel::Iau2006 => {
+            let t4 = t3 * t;
+            let t5 = t4 * t;
+            (
+                (46.998973 * t - 0.0334926 * t2 - 0.00012559 * t3 + 0.000000113 * t4
+                    - 0.0000000022 * t5)
+                    * ARCSEC,
+                (629546.7936 - 867.95758 * t + 0.157992 * t2 - 0.0005371 * t3 - 0.00004797 * t4
+                    + 0.000000072 * t5)
+                    * ARCSEC,
+                (5028.796195 * t + 1.1054348 * t2 + 0.00007964 * t3
+                    - 0.000023857 * t4
+                    - 0.0000000383 * t5)
+                    * ARCSEC,
+            )
+        }
+    }
+}
+
+/// Rotation from the mean ecliptic and equinox of date to those of J2000.
+pub fn ecliptic_date_to_j2000(jd: f64, model: PrecessionModel) -> Matrix {
+    let (pi, big_pi, p) = ecliptic_precession_angles(jd, model);
+    mat_mul(
+        &rotation_z(big_pi),
+        &mat_mul(&rotation_x(pi), &rotation_z(-(big_pi + p))),
+    )
+}
+
+/// Rotation from the mean ecliptic and equinox of J2000 to those of date, the
+/// inverse of [`ecliptic_date_to_j2000`].
+pub fn ecliptic_j2000_to_date(jd: f64, model: PrecessionModel) -> Matrix {
+    transpose(&ecliptic_date_to_j2000(jd, model))
+}
+
+/// Carry an ecliptic longitude and latitude in degrees from the J2000 frame
+/// to the mean ecliptic and equinox of `jd`.
+pub fn precess_from_j2000(
+    lon_deg: f64,
+    lat_deg: f64,
+    jd: f64,
+    model: PrecessionModel,
+) -> (f64, f64) {
+    let rotated = mat_vec(
+        &ecliptic_j2000_to_date(jd, model),
+        unit_vector(lon_deg, lat_deg),
+    );
+    spherical_degrees(rotated)
+}
+
+/// Carry an ecliptic longitude and latitude in degrees from the mean ecliptic
+/// and equinox of `jd` to the J2000 frame.
+pub fn precess_to_j2000(lon_deg: f64, lat_deg: f64, jd: f64, model: PrecessionModel) -> (f64, f64) {
+    let rotated = mat_vec(
+        &ecliptic_date_to_j2000(jd, model),
+        unit_vector(lon_deg, lat_deg),
+    );
+    spherical_degrees(rotated)
+}
+
+fn unit_vector(lon_deg: f64, lat_deg: f64) -> [f64; 3] {
+    let (lon, lat) = (lon_deg * DEG_TO_RAD, lat_deg * DEG_TO_RAD);
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn spherical_degrees([x, y, z]: [f64; 3]) -> (f64, f64) {
+    (
+        normalize_degrees(y.atan2(x) * RAD_TO_DEG),
+        z.atan2(x.hypot(y)) * RAD_TO_DEG,
+    )
+}
+
+pub(crate) fn mat_vec(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    std::array::from_fn(|row| (0..3).map(|n| m[row][n] * v[n]).sum())
+}
+
+pub(crate) fn mat_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    std::array::from_fn(|row| {
+        std::array::from_fn(|col| (0..3).map(|n| a[row][n] * b[n][col]).sum())
+    })
+}
+
+pub(crate) fn transpose(m: &Matrix) -> Matrix {
+    std::array::from_fn(|row| std::array::from_fn(|col| m[col][row]))
+}
+
+pub(crate) fn rotation_x(angle: f64) -> Matrix {
+    let (s, c) = angle.sin_cos();
+    [[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]]
+}
+
+pub(crate) fn rotation_z(angle: f64) -> Matrix {
+    let (s, c) = angle.sin_cos();
+    [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ecliptic_precession_matches_meeus_example_21c() {
+        // Venus at λ = 149.48194°, β = +1.76549° (J2000) carried to
+        // −214 June 30.0: λ = 118.704°, β = +1.615°
+        let jd = 1643074.5;
+        for model in [PrecessionModel::Iau1976, PrecessionModel::Iau2006] {
+            let (lon, lat) = precess_from_j2000(149.48194, 1.76549, jd, model);
+            assert!((lon - 118.704).abs() < 1e-3, "{:?}: λ {}", model, lon);
+            assert!((lat - 1.615).abs() < 1e-3, "{:?}: β {}", model, lat);
+        }
+    }
+
+    #[test]
+    fn precession_to_and_from_j2000_round_trips() {
+        let jd = J2000 - 50.0 * DAYS_PER_CENTURY;
+        let (lon, lat) = precess_from_j2000(203.84, -2.05, jd, PrecessionModel::Iau2006);
+        let (lon0, lat0) = precess_to_j2000(lon, lat, jd, PrecessionModel::Iau2006);
+        assert!((lon0 - 203.84).abs() < 1e-9 && (lat0 + 2.05).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,435 @@
+//! Rising, setting and twilight.
+//!
+//! A body rises or sets when its centre crosses a standard altitude made up
+//! of the horizontal refraction (34′), the semi-diameter when a limb rather
+//! than the centre is wanted, and the horizontal parallax, which matters for
+//! the Moon. The almanac definition takes the upper limb with refraction
+//! and parallax. The Hindu definition takes the centre of the disc on the
+//! geometric horizon, without refraction, parallax or the Moon's ecliptic
+//! latitude. The moment is found by iterating on the hour angle with the
+//! apparent right ascension and declination (Meeus, *Astronomical
+//! Algorithms*, ch. 15).
+//!
+//! Where the body does not cross the horizon during the day in question
+//! (polar day and night), the result says so instead of holding NaN.
+//!
+//! All `jd` arguments are JD(UT); longitudes are east and latitudes north,
+//! in degrees, as in [`crate::BirthData`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::ayanamsa::signed_degrees;
+use crate::{
+    get_apparent, local_sidereal_time, normalize_degrees, true_obliquity, ut_to_tt, weekday_index,
+    ApparentPlace, Result, VedicError, DEG_TO_RAD, RAD_TO_DEG,
+};
+
+/// Horizontal refraction at the horizon, degrees.
+pub const HORIZON_REFRACTION: f64 = 34.0 / 60.0;
+
+/// Rate of the hour angle of a fixed point, degrees per day of UT.
+const SIDEREAL_RATE: f64 = 360.985_647;
+
+/// Equatorial radius of the Earth, AU.
+const EARTH_RADIUS_AU: f64 = 6378.137 / 149_597_870.7;
+
+/// Semi-diameter of the Sun at 1 AU, degrees.
+const SUN_SEMI_DIAMETER: f64 = 959.63 / 3600.0;
+
+/// Ratio of the Moon's radius to the Earth's.
+const MOON_RADIUS_RATIO: f64 = 0.272_481;
+
+/// Rising or setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RiseSetEvent {
+    Rise,
+    Set,
+}
+
+/// Part of the disc that marks the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Disc {
+    Centre,
+    UpperLimb,
+    LowerLimb,
+}
+
+/// How a rise or set is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiseSetOptions {
+    pub disc: Disc,
+    /// Lift the body by the horizontal refraction.
+    pub refraction: bool,
+    /// Lower the body by its horizontal parallax, as seen from the surface.
+    pub parallax: bool,
+    /// Use the body's ecliptic latitude; without it the body is taken on
+    /// the ecliptic.
+    pub ecliptic_latitude: bool,
+}
+
+impl RiseSetOptions {
+    /// Upper limb on the refracted horizon, as in the astronomical almanacs.
+    pub const ALMANAC: Self = Self {
+        disc: Disc::UpperLimb,
+        refraction: true,
+        parallax: true,
+        ecliptic_latitude: true,
+    };
+
+    /// Centre of the disc on the geometric horizon, as in the Hindu
+    /// almanacs.
+    pub const HINDU: Self = Self {
+        disc: Disc::Centre,
+        refraction: false,
+        parallax: false,
+        ecliptic_latitude: false,
+    };
+}
+
+impl Default for RiseSetOptions {
+    fn default() -> Self {
+        Self::ALMANAC
+    }
+}
+
+/// Depth of the Sun's centre below the horizon that ends a twilight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Twilight {
+    /// 6°.
+    Civil,
+    /// 12°.
+    Nautical,
+    /// 18°.
+    Astronomical,
+}
+
+impl Twilight {
+    /// Altitude of the Sun's centre at the start of dawn or the end of dusk,
+    /// degrees.
+    pub fn altitude(self) -> f64 {
+        match self {
+            Twilight::Civil => -6.0,
+            Twilight::Nautical => -12.0,
+            Twilight::Astronomical => -18.0,
+        }
+    }
+}
+
+/// Outcome of a rise, set or twilight search.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum RiseSet {
+    /// The event happens at this JD(UT).
+    At(f64),
+    /// The body stays above the horizon: polar day for the Sun.
+    AlwaysAbove,
+    /// The body stays below the horizon: polar night for the Sun.
+    AlwaysBelow,
+}
+
+impl RiseSet {
+    /// The moment of the event, if there is one.
+    pub fn jd(self) -> Option<f64> {
+        match self {
+            RiseSet::At(jd) => Some(jd),
+            RiseSet::AlwaysAbove | RiseSet::AlwaysBelow => None,
+        }
+    }
+}
+
+/// First rise or set of `body` ("Sun", "Moon" or a planet) at or after
+/// `jd_ut` at the given place.
+pub fn rise_set(
+    body: &str,
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    event: RiseSetEvent,
+    options: RiseSetOptions,
+) -> Result<RiseSet> {
+    let altitude = |distance_au: f64| {
+        let parallax = (EARTH_RADIUS_AU / distance_au).asin() * RAD_TO_DEG;
+        let semi_diameter = match body {
+            "Sun" => SUN_SEMI_DIAMETER / distance_au,
+            "Moon" => (MOON_RADIUS_RATIO * (parallax * DEG_TO_RAD).sin()).asin() * RAD_TO_DEG,
+            _ => 0.0,
+        };
+        let limb = match options.disc {
+            Disc::Centre => 0.0,
+            Disc::UpperLimb => -semi_diameter,
+            Disc::LowerLimb => semi_diameter,
+        };
+        let refraction = if options.refraction {
+            HORIZON_REFRACTION
+        } else {
+            0.0
+        };
+        let parallax = if options.parallax { parallax } else { 0.0 };
+        limb - refraction + parallax
+    };
+    next_crossing(
+        body,
+        jd_ut,
+        longitude_deg,
+        latitude_deg,
+        &altitude,
+        options.ecliptic_latitude,
+        event,
+    )
+}
+
+/// First dawn (`Rise`) or dusk (`Set`) of the given twilight at or after
+/// `jd_ut` at the given place.
+pub fn twilight(
+    kind: Twilight,
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    event: RiseSetEvent,
+) -> Result<RiseSet> {
+    next_crossing(
+        "Sun",
+        jd_ut,
+        longitude_deg,
+        latitude_deg,
+        &|_| kind.altitude(),
+        true,
+        event,
+    )
+}
+
+/// First sunrise at or after `jd_ut` at the given place, by the definition
+/// in `options`. Fails where the Sun does not rise that day.
+pub fn sunrise(
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    options: RiseSetOptions,
+) -> Result<f64> {
+    sun_event(
+        jd_ut,
+        longitude_deg,
+        latitude_deg,
+        RiseSetEvent::Rise,
+        options,
+    )
+}
+
+/// First sunset at or after `jd_ut` at the given place, by the definition
+/// in `options`. Fails where the Sun does not set that day.
+pub fn sunset(
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    options: RiseSetOptions,
+) -> Result<f64> {
+    sun_event(
+        jd_ut,
+        longitude_deg,
+        latitude_deg,
+        RiseSetEvent::Set,
+        options,
+    )
+}
+
+/// Last sunrise at or before `jd_ut` at the given place, the start of the
+/// Hindu day (vara) in progress.
+pub fn previous_sunrise(
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    options: RiseSetOptions,
+) -> Result<f64> {
+    let mut rise = sunrise(jd_ut - 1.5, longitude_deg, latitude_deg, options)?;
+    loop {
+        let next = sunrise(rise + 0.01, longitude_deg, latitude_deg, options)?;
+        if next > jd_ut {
+            return Ok(rise);
+        }
+        rise = next;
+    }
+}
+
+/// Weekday of the Hindu day (vara) in progress at `jd_ut`, 0 = Sunday. The
+/// vara runs from sunrise to sunrise, so before dawn it is still the
+/// previous day's.
+pub fn vara_index(
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    options: RiseSetOptions,
+) -> Result<usize> {
+    let rise = previous_sunrise(jd_ut, longitude_deg, latitude_deg, options)?;
+    Ok(weekday_index(rise + longitude_deg / 360.0))
+}
+
+fn sun_event(
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    event: RiseSetEvent,
+    options: RiseSetOptions,
+) -> Result<f64> {
+    let outcome = rise_set("Sun", jd_ut, longitude_deg, latitude_deg, event, options)?;
+    outcome.jd().ok_or_else(|| {
+        VedicError::CalculationError(format!(
+            "no sun{} at latitude {}° near JD {}: the Sun is {}",
+            if event == RiseSetEvent::Rise {
+                "rise"
+            } else {
+                "set"
+            },
+            latitude_deg,
+            jd_ut,
+            if outcome == RiseSet::AlwaysAbove {
+                "always above the horizon"
+            } else {
+                "always below the horizon"
+            }
+        ))
+    })
+}
+
+/// First moment at or after `jd_ut` at which `body` crosses the altitude
+/// given by `altitude` for its distance in AU.
+fn next_crossing(
+    body: &str,
+    jd_ut: f64,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    altitude: &dyn Fn(f64) -> f64,
+    ecliptic_latitude: bool,
+    event: RiseSetEvent,
+) -> Result<RiseSet> {
+    let (sin_lat, cos_lat) = (latitude_deg * DEG_TO_RAD).sin_cos();
+    // hour angle of the crossing, east (rising) negative
+    let event_hour_angle = |(_, dec_deg, distance): (f64, f64, f64)| {
+        let dec = dec_deg * DEG_TO_RAD;
+        let cos_h =
+            ((altitude(distance) * DEG_TO_RAD).sin() - sin_lat * dec.sin()) / (cos_lat * dec.cos());
+        if cos_h > 1.0 {
+            return Err(RiseSet::AlwaysBelow);
+        }
+        if cos_h < -1.0 {
+            return Err(RiseSet::AlwaysAbove);
+        }
+        let h = cos_h.acos() * RAD_TO_DEG;
+        Ok(match event {
+            RiseSetEvent::Rise => -h,
+            RiseSetEvent::Set => h,
+        })
+    };
+    let hour_angle = |jd: f64, ra_deg: f64| local_sidereal_time(jd, longitude_deg) - ra_deg;
+    let place = |jd: f64| equatorial(body, jd, ecliptic_latitude);
+
+    let mut start = jd_ut;
+    loop {
+        let here = place(start)?;
+        let target = match event_hour_angle(here) {
+            Ok(target) => target,
+            Err(outcome) => return Ok(outcome),
+        };
+        let mut t = start + normalize_degrees(target - hour_angle(start, here.0)) / SIDEREAL_RATE;
+        for _ in 0..20 {
+            let here = place(t)?;
+            let target = match event_hour_angle(here) {
+                Ok(target) => target,
+                Err(outcome) => return Ok(outcome),
+            };
+            let change = signed_degrees(target - hour_angle(t, here.0));
+            t += change / SIDEREAL_RATE;
+            if change.abs() < 1e-6 {
+                break;
+            }
+        }
+        if t >= jd_ut {
+            return Ok(RiseSet::At(t));
+        }
+        // refinement slipped back past the start; look half a day on
+        start = t + 0.5;
+    }
+}
+
+/// Apparent right ascension and declination in degrees, and distance in AU.
+fn equatorial(body: &str, jd_ut: f64, ecliptic_latitude: bool) -> Result<(f64, f64, f64)> {
+    let jd_tt = ut_to_tt(jd_ut);
+    let [lon, lat, distance, ..] = get_apparent(body, jd_tt, ApparentPlace::APPARENT)?;
+    let lat = if ecliptic_latitude { lat } else { 0.0 };
+    let eps = true_obliquity(jd_tt) * DEG_TO_RAD;
+    let ra = (lon.sin() * eps.cos() - lat.tan() * eps.sin()).atan2(lon.cos());
+    let dec = (lat.sin() * eps.cos() + lat.cos() * eps.sin() * lon.sin()).asin();
+    Ok((ra * RAD_TO_DEG, dec * RAD_TO_DEG, distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELHI: (f64, f64) = (77.209, 28.6139);
+
+    /// Hours after 0h IST on 2024 January 1.
+    fn ist_hours(jd: f64) -> f64 {
+        (jd - 2460310.5) * 24.0 + 5.5
+    }
+
+    #[test]
+    fn delhi_sunrise_and_sunset_match_the_almanac() {
+        // New Delhi, 2024 January 1: sunrise 07:14 and sunset 17:36 IST
+        // (Drik Panchang), both to the minute
+        let (lon, lat) = DELHI;
+        let midnight = 2460310.5 - 5.5 / 24.0;
+        let rise = sunrise(midnight, lon, lat, RiseSetOptions::ALMANAC).unwrap();
+        let set = sunset(rise, lon, lat, RiseSetOptions::ALMANAC).unwrap();
+        assert!((ist_hours(rise) - (7.0 + 14.0 / 60.0)).abs() < 1.0 / 60.0);
+        assert!((ist_hours(set) - (17.0 + 36.0 / 60.0)).abs() < 1.0 / 60.0);
+
+        // the centre of the disc on the unrefracted horizon comes some four
+        // minutes later in the morning and earlier in the evening
+        let hindu_rise = sunrise(midnight, lon, lat, RiseSetOptions::HINDU).unwrap();
+        let hindu_set = sunset(rise, lon, lat, RiseSetOptions::HINDU).unwrap();
+        assert!(((hindu_rise - rise) * 1440.0 - 4.2).abs() < 0.5);
+        assert!(((set - hindu_set) * 1440.0 - 4.2).abs() < 0.5);
+    }
+
+    #[test]
+    fn vara_changes_at_sunrise_not_midnight() {
+        // Monday 2024 January 1 in Delhi: still Sunday's vara at 06:00 IST,
+        // Monday's from the 07:14 sunrise
+        let (lon, lat) = DELHI;
+        let at = |hours: f64| 2460310.5 + (hours - 5.5) / 24.0;
+        assert_eq!(
+            vara_index(at(6.0), lon, lat, RiseSetOptions::ALMANAC).unwrap(),
+            0
+        );
+        assert_eq!(
+            vara_index(at(8.0), lon, lat, RiseSetOptions::ALMANAC).unwrap(),
+            1
+        );
+        // at 07:16 the upper limb is up but the centre is not
+        assert_eq!(
+            vara_index(at(7.27), lon, lat, RiseSetOptions::ALMANAC).unwrap(),
+            1
+        );
+        assert_eq!(
+            vara_index(at(7.27), lon, lat, RiseSetOptions::HINDU).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn polar_day_and_night_are_reported() {
+        // Tromsø, 69.65° N: midnight sun at the June solstice, polar night
+        // at the December one
+        let (lon, lat) = (18.96, 69.65);
+        let june = 2460482.5;
+        let december = 2460300.5;
+        for options in [RiseSetOptions::ALMANAC, RiseSetOptions::HINDU] {
+            let summer = rise_set("Sun", june, lon, lat, RiseSetEvent::Rise, options).unwrap();
+            assert_eq!(summer, RiseSet::AlwaysAbove);
+            assert_eq!(summer.jd(), None);
+            let winter = rise_set("Sun", december, lon, lat, RiseSetEvent::Set, options).unwrap();
+            assert_eq!(winter, RiseSet::AlwaysBelow);
+        }
+        assert!(sunrise(june, lon, lat, RiseSetOptions::ALMANAC).is_err());
+        assert!(sunset(december, lon, lat, RiseSetOptions::ALMANAC).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Local solar time: Local Mean Time (LMT) and Local Apparent Time (LAT).
+//!
+//! LMT is UTC shifted by four minutes per degree of east longitude. LAT
+//! follows the true Sun and differs from LMT by the equation of time
+//! (Meeus, *Astronomical Algorithms*, ch. 28), so that apparent noon is the
+//! Sun's upper transit. Both are returned as naive clock readings, since
+//! neither is a time zone.
+
+use chrono::{Duration, NaiveDateTime};
+
+use crate::{
+    get_apparent, mean_obliquity, normalize_degrees, nutation, ut_to_tt, ApparentPlace, BirthData,
+    Result, DEG_TO_RAD, J2000, RAD_TO_DEG,
+};
+
+/// Minutes of time per degree of longitude or hour angle.
+const MINUTES_PER_DEGREE: f64 = 4.0;
+
+/// Equation of time in minutes at `jd_ut`, JD(UT): apparent solar time less
+/// mean solar time. Positive when the true Sun is ahead of the mean Sun.
+pub fn equation_of_time(jd_ut: f64) -> Result<f64> {
+    let jd_tt = ut_to_tt(jd_ut);
+    let tau = (jd_tt - J2000) / 365250.0;
+
+    // Mean longitude of the Sun, eq. 28.2
+    let l0 = 280.466_456_7
+        + 360_007.698_277_9 * tau
+        + 0.030_320_28 * tau.powi(2)
+        + tau.powi(3) / 49_931.0
+        - tau.powi(4) / 15_300.0
+        - tau.powi(5) / 2_000_000.0;
+
+    let [lon, lat, ..] = get_apparent("Sun", jd_tt, ApparentPlace::APPARENT)?;
+    let (dpsi, deps) = nutation(jd_tt);
+    let eps = (mean_obliquity(jd_tt) + deps) * DEG_TO_RAD;
+    let ra = (lon.sin() * eps.cos() - lat.tan() * eps.sin()).atan2(lon.cos()) * RAD_TO_DEG;
+
+    let mut e = normalize_degrees(l0 - 0.005_718_3 - ra + dpsi * eps.cos());
+    if e > 180.0 {
+        e -= 360.0;
+    }
+    Ok(e * MINUTES_PER_DEGREE)
+}
+
+impl BirthData {
+    /// Local Mean Time at the birth place: UTC plus four minutes per degree
+    /// of east longitude.
+    pub fn local_mean_time(&self) -> NaiveDateTime {
+        self.datetime.naive_utc() + minutes(self.longitude * MINUTES_PER_DEGREE)
+    }
+
+    /// Local Apparent Time at the birth place: Local Mean Time corrected by
+    /// the equation of time.
+    pub fn local_apparent_time(&self) -> Result<NaiveDateTime> {
+        Ok(self.local_mean_time() + minutes(equation_of_time(self.to_jd()?)?))
+    }
+}
+
+fn minutes(value: f64) -> Duration {
+    Duration::nanoseconds((value * 60.0e9).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tt_to_ut;
+
+    #[test]
+    fn equation_of_time_matches_meeus_example_28b() {
+        // 1992 October 13, 0h TD: E = +3.427351° = 13m42.6s
+        let e = equation_of_time(tt_to_ut(2448908.5)).unwrap();
+        assert!(
+            (e - 3.427351 * MINUTES_PER_DEGREE).abs() < 0.1 / 60.0,
+            "E {}",
+            e
+        );
+    }
+}
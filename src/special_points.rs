@@ -0,0 +1,254 @@
+//! Upagrahas and special lagnas of the Parashari system.
+//!
+//! The five non-luminous upagrahas (Dhuma, Vyatipata, Parivesha, Indrachapa
+//! and Upaketu) follow from the Sun's longitude alone.
+//! Gulika and Mandi belong to Saturn's eighth of the day or night: the span
+//! from sunrise to sunset, or sunset to the next sunrise, is cut into eight
+//! portions ruled in weekday order from the lord of the day (by night from
+//! the fifth lord), the eighth having no lord. Gulika is the lagna rising at
+//! the start of Saturn's portion and Mandi the lagna rising at its middle.
+//! Hora, Ghati and Bhava Lagna advance from the Sun's longitude at sunrise by
+//! one sign per hour, per ghati and per five ghatis. Sree Lagna adds to the
+//! lagna the Moon's progress through its nakshatra, scaled to the zodiac.
+//!
+//! Every point is a sidereal longitude, so [`SpecialPoints::positions`] can
+//! be appended to the planets given to any divisional chart builder.
+
+use serde::Serialize;
+
+use crate::rise_set::{previous_sunrise, sunrise, sunset, RiseSetOptions};
+use crate::{
+    ascendant, compute_apparent_position, local_sidereal_time, normalize_degrees, true_obliquity,
+    ut_to_tt, weekday_index, ApparentPlace, Ayanamsa, BirthData, PlanetPosition, Result,
+    VedicError,
+};
+
+/// Minutes for Hora Lagna to pass through a sign: one hour.
+pub const HORA_LAGNA_MINUTES_PER_SIGN: f64 = 60.0;
+
+/// Minutes for Ghati Lagna to pass through a sign: one ghati.
+pub const GHATI_LAGNA_MINUTES_PER_SIGN: f64 = 24.0;
+
+/// Minutes for Bhava Lagna to pass through a sign: five ghatis.
+pub const BHAVA_LAGNA_MINUTES_PER_SIGN: f64 = 120.0;
+
+/// Arc of one nakshatra, degrees.
+const NAKSHATRA_SPAN: f64 = 360.0 / 27.0;
+
+/// Sidereal longitudes of the upagrahas and special lagnas, degrees.
+#[derive(Debug, Clone, Serialize)]
+pub struct SpecialPoints {
+    pub gulika: f64,
+    pub mandi: f64,
+    pub hora_lagna: f64,
+    pub ghati_lagna: f64,
+    pub bhava_lagna: f64,
+    pub sree_lagna: f64,
+    pub dhuma: f64,
+    pub vyatipata: f64,
+    pub parivesha: f64,
+    pub indrachapa: f64,
+    pub upaketu: f64,
+}
+
+impl SpecialPoints {
+    /// Every point with its name, in the order of the fields.
+    pub fn named(&self) -> [(&'static str, f64); 11] {
+        [
+            ("Gulika", self.gulika),
+            ("Mandi", self.mandi),
+            ("Hora Lagna", self.hora_lagna),
+            ("Ghati Lagna", self.ghati_lagna),
+            ("Bhava Lagna", self.bhava_lagna),
+            ("Sree Lagna", self.sree_lagna),
+            ("Dhuma", self.dhuma),
+            ("Vyatipata", self.vyatipata),
+            ("Parivesha", self.parivesha),
+            ("Indrachapa", self.indrachapa),
+            ("Upaketu", self.upaketu),
+        ]
+    }
+
+    /// The points as motionless positions on the ecliptic, ready to be
+    /// placed in a [`crate::Chart`] alongside the planets.
+    pub fn positions(&self) -> Vec<PlanetPosition> {
+        self.named()
+            .into_iter()
+            .map(|(name, longitude)| PlanetPosition {
+                name: name.to_string(),
+                sidereal_long_deg: longitude,
+                latitude_deg: 0.0,
+                distance_au: 0.0,
+                speed_deg_per_day: 0.0,
+                retrograde: false,
+                stationary: false,
+            })
+            .collect()
+    }
+}
+
+/// Dhuma, Vyatipata, Parivesha, Indrachapa and Upaketu for a sidereal Sun.
+pub fn sun_upagrahas(sun_deg: f64) -> [f64; 5] {
+    let dhuma = normalize_degrees(sun_deg + 133.0 + 20.0 / 60.0);
+    let vyatipata = normalize_degrees(360.0 - dhuma);
+    let parivesha = normalize_degrees(vyatipata + 180.0);
+    let indrachapa = normalize_degrees(360.0 - parivesha);
+    let upaketu = normalize_degrees(indrachapa + 16.0 + 40.0 / 60.0);
+    [dhuma, vyatipata, parivesha, indrachapa, upaketu]
+}
+
+/// A lagna that starts from the Sun at sunrise and moves one sign every
+/// `minutes_per_sign`; see [`HORA_LAGNA_MINUTES_PER_SIGN`] and its siblings.
+pub fn time_lagna(
+    sun_at_sunrise_deg: f64,
+    minutes_since_sunrise: f64,
+    minutes_per_sign: f64,
+) -> f64 {
+    normalize_degrees(sun_at_sunrise_deg + 30.0 * minutes_since_sunrise / minutes_per_sign)
+}
+
+/// Sree Lagna: the lagna advanced by the fraction of its nakshatra the Moon
+/// has covered, taken of the whole zodiac.
+pub fn sree_lagna(asc_deg: f64, moon_deg: f64) -> f64 {
+    let progress = normalize_degrees(moon_deg).rem_euclid(NAKSHATRA_SPAN) / NAKSHATRA_SPAN;
+    normalize_degrees(asc_deg + 360.0 * progress)
+}
+
+/// Portion (0–7) of the day or night ruled by Saturn, whose start rises as
+/// Gulika, for the weekday `day_lord` (0 = Sunday) of the Hindu day.
+pub fn saturn_portion(day_lord: usize, by_night: bool) -> usize {
+    let first_lord = if by_night {
+        (day_lord + 4) % 7
+    } else {
+        day_lord
+    };
+    (6 + 7 - first_lord) % 7
+}
+
+/// Upagrahas and special lagnas at the birth moment and place. The Sun and
+/// Moon are taken from `planets`; the lagnas from the ayanamsa given; the
+/// day and night from sunrise and sunset as defined by `options`.
+pub fn compute_special_points(
+    birth_data: &BirthData,
+    planets: &[PlanetPosition],
+    ayanamsa: Ayanamsa,
+    options: RiseSetOptions,
+) -> Result<SpecialPoints> {
+    let longitude_of = |name: &str| {
+        planets
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.sidereal_long_deg)
+            .ok_or_else(|| VedicError::CalculationError(format!("{} position not found", name)))
+    };
+    let sun = longitude_of("Sun")?;
+    let moon = longitude_of("Moon")?;
+
+    let (lon, lat) = (birth_data.longitude, birth_data.latitude);
+    let jd = birth_data.to_jd()?;
+    let lagna_at = |jd_ut: f64| -> Result<f64> {
+        let jd_tt = ut_to_tt(jd_ut);
+        let asc = ascendant(local_sidereal_time(jd_ut, lon), lat, true_obliquity(jd_tt))?;
+        Ok(ayanamsa.to_sidereal(asc, jd_tt))
+    };
+
+    // Saturn's eighth of the day or the night in progress
+    let rise = previous_sunrise(jd, lon, lat, options)?;
+    let set = sunset(rise, lon, lat, options)?;
+    let day_lord = weekday_index(rise + lon / 360.0);
+    let by_night = jd >= set;
+    let (span_start, span_end) = if by_night {
+        (set, sunrise(set, lon, lat, options)?)
+    } else {
+        (rise, set)
+    };
+    let portion = (span_end - span_start) / 8.0;
+    let saturn_start = span_start + saturn_portion(day_lord, by_night) as f64 * portion;
+
+    let sun_at_sunrise =
+        compute_apparent_position("Sun", ut_to_tt(rise), ApparentPlace::APPARENT, ayanamsa)?
+            .sidereal_long_deg;
+    let minutes = (jd - rise) * 1440.0;
+    let [dhuma, vyatipata, parivesha, indrachapa, upaketu] = sun_upagrahas(sun);
+
+    Ok(SpecialPoints {
+        gulika: lagna_at(saturn_start)?,
+        mandi: lagna_at(saturn_start + portion / 2.0)?,
+        hora_lagna: time_lagna(sun_at_sunrise, minutes, HORA_LAGNA_MINUTES_PER_SIGN),
+        ghati_lagna: time_lagna(sun_at_sunrise, minutes, GHATI_LAGNA_MINUTES_PER_SIGN),
+        bhava_lagna: time_lagna(sun_at_sunrise, minutes, BHAVA_LAGNA_MINUTES_PER_SIGN),
+        sree_lagna: sree_lagna(lagna_at(jd)?, moon),
+        dhuma,
+        vyatipata,
+        parivesha,
+        indrachapa,
+        upaketu,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn saturn_portion_follows_the_classical_table() {
+        // Gulika's portion by day from Sunday: 7th, 6th, 5th, 4th, 3rd,
+        // 2nd, 1st; by night: 3rd, 2nd, 1st, 7th, 6th, 5th, 4th
+        let day: Vec<_> = (0..7).map(|lord| saturn_portion(lord, false) + 1).collect();
+        let night: Vec<_> = (0..7).map(|lord| saturn_portion(lord, true) + 1).collect();
+        assert_eq!(day, [7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(night, [3, 2, 1, 7, 6, 5, 4]);
+    }
+
+    #[test]
+    fn gulika_and_mandi_for_a_saturday_in_delhi() {
+        // Saturday 2024 January 6 in Delhi: by day Saturn rules the first
+        // eighth, so Gulika rises with the Sun and Mandi a sixteenth of the
+        // day later; by night Saturn rules the fourth eighth
+        let (lon, lat) = (77.209, 28.6139);
+        let ayanamsa = Ayanamsa::Lahiri;
+        let options = RiseSetOptions::HINDU;
+        let lagna_at = |jd_ut: f64| {
+            let jd_tt = ut_to_tt(jd_ut);
+            let asc = ascendant(local_sidereal_time(jd_ut, lon), lat, true_obliquity(jd_tt));
+            ayanamsa.to_sidereal(asc.unwrap(), jd_tt)
+        };
+        let points_at = |hour: u32| {
+            let birth = BirthData {
+                datetime: Utc.with_ymd_and_hms(2024, 1, 6, hour, 0, 0).unwrap(),
+                longitude: lon,
+                latitude: lat,
+            };
+            let jd_tt = birth.to_jd_tt().unwrap();
+            let planets: Vec<_> = ["Sun", "Moon"]
+                .into_iter()
+                .map(|name| {
+                    compute_apparent_position(name, jd_tt, ApparentPlace::APPARENT, ayanamsa)
+                        .unwrap()
+                })
+                .collect();
+            compute_special_points(&birth, &planets, ayanamsa, options).unwrap()
+        };
+
+        let rise = sunrise(2460315.5, lon, lat, options).unwrap();
+        let set = sunset(rise, lon, lat, options).unwrap();
+        assert_eq!(weekday_index(rise + lon / 360.0), 6);
+
+        let day = points_at(6);
+        assert!((day.gulika - lagna_at(rise)).abs() < 1e-6);
+        assert!((day.mandi - lagna_at(rise + (set - rise) / 16.0)).abs() < 1e-6);
+        // the lagna at sunrise is the Sun's own degree, give or take the
+        // minutes the disc takes to clear the horizon
+        let sun =
+            compute_apparent_position("Sun", ut_to_tt(rise), ApparentPlace::APPARENT, ayanamsa)
+                .unwrap();
+        assert!((day.gulika - sun.sidereal_long_deg).abs() < 1.5);
+
+        let next_rise = sunrise(set, lon, lat, options).unwrap();
+        let night = points_at(17);
+        let portion = (next_rise - set) / 8.0;
+        assert!((night.gulika - lagna_at(set + 3.0 * portion)).abs() < 1e-6);
+        assert!((night.mandi - lagna_at(set + 3.5 * portion)).abs() < 1e-6);
+    }
+}
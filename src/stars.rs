@@ -0,0 +1,181 @@
+//! A small catalogue of fixed stars used as sidereal anchors.
+//!
+//! Positions are Hipparcos (ICRS, epoch J2000) right ascension and
+//! declination with their proper motions. A star is moved along its proper
+//! motion, turned onto the ecliptic of J2000 and then precessed to the mean
+//! ecliptic and equinox of date with the IAU 2006 (Capitaine) angles, the
+//! default [`PrecessionModel`].
+//!
+//! All `jd` arguments are JD(TT).
+
+use serde::Serialize;
+
+use crate::ayanamsa::signed_degrees;
+use crate::precession::{ecliptic_j2000_to_date, mat_vec, rotation_x, PrecessionModel};
+use crate::{normalize_degrees, Result, VedicError, DEG_TO_RAD, J2000, RAD_TO_DEG};
+
+/// Obliquity of the ecliptic at J2000 (IAU 2006), degrees.
+const OBLIQUITY_2000: f64 = 23.439_279_4;
+
+/// Milliarcseconds per degree.
+const MAS_PER_DEGREE: f64 = 3_600_000.0;
+
+/// A star with its J2000 place and proper motion.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct FixedStar {
+    pub name: &'static str,
+    /// Nakshatra whose yogatara (junction star) this is.
+    pub nakshatra: &'static str,
+    pub ra_deg: f64,
+    pub dec_deg: f64,
+    /// Proper motion in right ascension, μα·cos δ, mas per year.
+    pub pm_ra_mas_per_year: f64,
+    /// Proper motion in declination, mas per year.
+    pub pm_dec_mas_per_year: f64,
+}
+
+/// Aldebaran, α Tauri.
+pub const ALDEBARAN: FixedStar = FixedStar {
+    name: "Aldebaran",
+    nakshatra: "Rohini",
+    ra_deg: 68.980_163,
+    dec_deg: 16.509_302,
+    pm_ra_mas_per_year: 63.45,
+    pm_dec_mas_per_year: -188.94,
+};
+
+/// Asellus Australis, δ Cancri.
+pub const DELTA_CANCRI: FixedStar = FixedStar {
+    name: "Delta Cancri",
+    nakshatra: "Pushya",
+    ra_deg: 131.171_248,
+    dec_deg: 18.154_309,
+    pm_ra_mas_per_year: -17.10,
+    pm_dec_mas_per_year: -228.46,
+};
+
+/// Regulus, α Leonis.
+pub const REGULUS: FixedStar = FixedStar {
+    name: "Regulus",
+    nakshatra: "Magha",
+    ra_deg: 152.092_962,
+    dec_deg: 11.967_208,
+    pm_ra_mas_per_year: -248.73,
+    pm_dec_mas_per_year: 5.59,
+};
+
+/// Spica, α Virginis.
+pub const SPICA: FixedStar = FixedStar {
+    name: "Spica",
+    nakshatra: "Chitra",
+    ra_deg: 201.298_247,
+    dec_deg: -11.161_322,
+    pm_ra_mas_per_year: -42.35,
+    pm_dec_mas_per_year: -30.67,
+};
+
+/// Antares, α Scorpii.
+pub const ANTARES: FixedStar = FixedStar {
+    name: "Antares",
+    nakshatra: "Jyeshtha",
+    ra_deg: 247.351_915,
+    dec_deg: -26.432_003,
+    pm_ra_mas_per_year: -12.11,
+    pm_dec_mas_per_year: -23.30,
+};
+
+/// Every star of the catalogue, in order of right ascension.
+pub const FIXED_STARS: [FixedStar; 5] = [ALDEBARAN, DELTA_CANCRI, REGULUS, SPICA, ANTARES];
+
+/// Look up a catalogue star by name.
+pub fn find_star(name: &str) -> Result<&'static FixedStar> {
+    FIXED_STARS
+        .iter()
+        .find(|star| star.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| VedicError::DataError(format!("star {} is not in the catalogue", name)))
+}
+
+impl FixedStar {
+    /// Ecliptic longitude and latitude in degrees at `jd`, referred to the
+    /// mean ecliptic and equinox of date.
+    pub fn ecliptic_position(&self, jd: f64) -> (f64, f64) {
+        let years = (jd - J2000) / 365.25;
+        let cos_dec = (self.dec_deg * DEG_TO_RAD).cos();
+        let ra = (self.ra_deg + self.pm_ra_mas_per_year * years / (MAS_PER_DEGREE * cos_dec))
+            * DEG_TO_RAD;
+        let dec = (self.dec_deg + self.pm_dec_mas_per_year * years / MAS_PER_DEGREE) * DEG_TO_RAD;
+
+        let equatorial = [dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin()];
+        let ecliptic_2000 = mat_vec(&rotation_x(-OBLIQUITY_2000 * DEG_TO_RAD), equatorial);
+        let [x, y, z] = mat_vec(
+            &ecliptic_j2000_to_date(jd, PrecessionModel::default()),
+            ecliptic_2000,
+        );
+
+        (
+            normalize_degrees(y.atan2(x) * RAD_TO_DEG),
+            z.atan2(x.hypot(y)) * RAD_TO_DEG,
+        )
+    }
+}
+
+/// Ayanamsa in degrees that puts `star` at `sidereal_long_deg` at `jd`.
+pub fn star_ayanamsa(star: &FixedStar, sidereal_long_deg: f64, jd: f64) -> f64 {
+    let (longitude, _) = star.ecliptic_position(jd);
+    signed_degrees(longitude - sidereal_long_deg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn j2000_longitudes_match_the_published_star_places() {
+        // ecliptic longitudes for J2000 as tabulated to the minute:
+        // Aldebaran 9°47′ Gemini, Regulus 29°50′ Leo, Spica 23°50′ Libra,
+        // Antares 9°46′ Sagittarius
+        for (star, longitude) in [
+            (ALDEBARAN, 69.0 + 47.0 / 60.0),
+            (REGULUS, 149.0 + 50.0 / 60.0),
+            (SPICA, 203.0 + 50.0 / 60.0),
+            (ANTARES, 249.0 + 46.0 / 60.0),
+        ] {
+            let (lon, _) = star.ecliptic_position(J2000);
+            assert!(
+                (lon - longitude).abs() < 1.0 / 60.0,
+                "{}: {}",
+                star.name,
+                lon
+            );
+        }
+        let (_, lat) = SPICA.ecliptic_position(J2000);
+        assert!((lat + (2.0 + 3.0 / 60.0)).abs() < 1.0 / 60.0, "{}", lat);
+    }
+
+    #[test]
+    fn spica_moves_by_its_catalogue_proper_motion() {
+        // against a copy of Spica without proper motion, the star drifts
+        // by |μ| = 52.3 mas a year, whatever the precession does
+        let fixed = FixedStar {
+            pm_ra_mas_per_year: 0.0,
+            pm_dec_mas_per_year: 0.0,
+            ..SPICA
+        };
+        let jd = J2000 + 1000.0 * 365.25;
+        let (lon, lat) = SPICA.ecliptic_position(jd);
+        let (lon0, lat0) = fixed.ecliptic_position(jd);
+        let drift = ((lon - lon0) * (lat * DEG_TO_RAD).cos()).hypot(lat - lat0);
+        let expected = SPICA.pm_ra_mas_per_year.hypot(SPICA.pm_dec_mas_per_year) * 1000.0;
+        assert!(
+            (drift * MAS_PER_DEGREE - expected).abs() < 100.0,
+            "{}",
+            drift
+        );
+    }
+
+    #[test]
+    fn stars_are_found_by_name() {
+        assert_eq!(find_star("spica").unwrap().nakshatra, "Chitra");
+        assert!(find_star("Vega").is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! Start and end times of the panchanga elements.
+//!
+//! Each element advances with an angle: the elongation Moon − Sun for the
+//! tithi (12° each) and the karana (6°), the Moon's sidereal longitude for
+//! the nakshatra (13°20′) and the sum Moon + Sun for the yoga (13°20′). An
+//! element changes when its angle crosses a multiple of its span; that
+//! instant is found by Newton iteration on the same Sun and Moon used by
+//! [`crate::compute_panchanga`], and the element in force between two
+//! changes is read with [`crate::compute_tithi`] and its siblings.
+//!
+//! All `jd` arguments are JD(UT).
+
+use chrono::{DateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+use crate::ayanamsa::signed_degrees;
+use crate::{
+    compute_karana, compute_nakshatra, compute_tithi, compute_yoga, jd_to_datetime,
+    normalize_degrees, sun_moon_sidereal, tithi_name, Ayanamsa, Result, VedicError,
+};
+
+/// Mean synodic motion of the Moon, degrees per day.
+const ELONGATION_RATE: f64 = 12.190_749;
+
+/// Mean sidereal motion of the Moon, degrees per day.
+const MOON_RATE: f64 = 13.176_358;
+
+/// Mean motion of the Sun, degrees per day.
+const SUN_RATE: f64 = 0.985_647;
+
+/// A limb of the panchanga that changes with the Sun and Moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PanchangaElement {
+    Tithi,
+    Nakshatra,
+    Yoga,
+    Karana,
+}
+
+impl PanchangaElement {
+    /// Arc of the angle covered by one element, degrees.
+    pub fn span(self) -> f64 {
+        match self {
+            PanchangaElement::Tithi => 12.0,
+            PanchangaElement::Karana => 6.0,
+            PanchangaElement::Nakshatra | PanchangaElement::Yoga => 360.0 / 27.0,
+        }
+    }
+
+    /// The angle the element follows at `jd_ut`, degrees in [0, 360).
+    pub fn angle(self, jd_ut: f64, ayanamsa: Ayanamsa) -> f64 {
+        let (sun, moon) = sun_moon_sidereal(jd_ut, ayanamsa);
+        match self {
+            PanchangaElement::Tithi | PanchangaElement::Karana => normalize_degrees(moon - sun),
+            PanchangaElement::Nakshatra => normalize_degrees(moon),
+            PanchangaElement::Yoga => normalize_degrees(moon + sun),
+        }
+    }
+
+    fn mean_rate(self) -> f64 {
+        match self {
+            PanchangaElement::Tithi | PanchangaElement::Karana => ELONGATION_RATE,
+            PanchangaElement::Nakshatra => MOON_RATE,
+            PanchangaElement::Yoga => MOON_RATE + SUN_RATE,
+        }
+    }
+
+    /// Number (from 1) and name of the element in force at `jd_ut`.
+    fn identify(self, jd_ut: f64, ayanamsa: Ayanamsa) -> (u8, String) {
+        match self {
+            PanchangaElement::Tithi => {
+                let (number, _) = compute_tithi(jd_ut);
+                (number, tithi_name(number).to_string())
+            }
+            PanchangaElement::Nakshatra => {
+                let (index, name) = compute_nakshatra(jd_ut, ayanamsa);
+                (index, name.to_string())
+            }
+            PanchangaElement::Yoga => {
+                let (index, name) = compute_yoga(jd_ut, ayanamsa);
+                (index, name.to_string())
+            }
+            PanchangaElement::Karana => {
+                let (index, name) = compute_karana(jd_ut);
+                (index, name.to_string())
+            }
+        }
+    }
+}
+
+/// One element and the interval it is in force.
+#[derive(Debug, Clone, Serialize)]
+pub struct ElementSpan {
+    pub element: PanchangaElement,
+    /// Number as returned by the matching `compute_*` function.
+    pub index: u8,
+    pub name: String,
+    /// JD(UT) at which the element begins.
+    pub start: f64,
+    /// JD(UT) at which the element ends.
+    pub end: f64,
+}
+
+impl ElementSpan {
+    /// Start of the element as a timestamp in `tz`.
+    pub fn start_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.start, tz)
+    }
+
+    /// End of the element as a timestamp in `tz`.
+    pub fn end_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Result<DateTime<Tz>> {
+        jd_to_datetime(self.end, tz)
+    }
+}
+
+/// The tithis of one Hindu day, from a sunrise to the next.
+#[derive(Debug, Clone, Serialize)]
+pub struct TithiDay {
+    pub sunrise: f64,
+    pub next_sunrise: f64,
+    /// Every tithi in force during the day, in order.
+    pub tithis: Vec<ElementSpan>,
+    /// Tithis that begin after the sunrise and end before the next one, so
+    /// that no day is named after them.
+    pub kshaya: Vec<u8>,
+    /// Whether the tithi of this sunrise still runs at the next one and so
+    /// names two days.
+    pub adhika: bool,
+}
+
+/// JD(UT) at which the element in force at `jd_ut` ends.
+pub fn element_end(element: PanchangaElement, jd_ut: f64, ayanamsa: Ayanamsa) -> Result<f64> {
+    let angle = element.angle(jd_ut, ayanamsa);
+    let target = ((angle / element.span()).floor() + 1.0) * element.span();
+    let guess = jd_ut + (target - angle) / element.mean_rate();
+    crossing(element, guess, target, ayanamsa)
+}
+
+/// JD(UT) at which the element in force at `jd_ut` began.
+pub fn element_start(element: PanchangaElement, jd_ut: f64, ayanamsa: Ayanamsa) -> Result<f64> {
+    let angle = element.angle(jd_ut, ayanamsa);
+    let target = (angle / element.span()).floor() * element.span();
+    let guess = jd_ut - (angle - target) / element.mean_rate();
+    crossing(element, guess, target, ayanamsa)
+}
+
+/// The instance of `element` in force at `jd_ut`, with its start and end.
+pub fn element_at(
+    element: PanchangaElement,
+    jd_ut: f64,
+    ayanamsa: Ayanamsa,
+) -> Result<ElementSpan> {
+    let start = element_start(element, jd_ut, ayanamsa)?;
+    let end = element_end(element, jd_ut, ayanamsa)?;
+    let (index, name) = element.identify((start + end) / 2.0, ayanamsa);
+    Ok(ElementSpan {
+        element,
+        index,
+        name,
+        start,
+        end,
+    })
+}
+
+/// Every instance of `element` in force between `from_jd` and `to_jd`, in
+/// order, with its full start and end.
+pub fn element_spans(
+    element: PanchangaElement,
+    from_jd: f64,
+    to_jd: f64,
+    ayanamsa: Ayanamsa,
+) -> Result<Vec<ElementSpan>> {
+    let mut spans = Vec::new();
+    let mut start = element_start(element, from_jd, ayanamsa)?;
+    let mut jd = from_jd;
+    loop {
+        let end = element_end(element, jd, ayanamsa)?;
+        let (index, name) = element.identify((start + end) / 2.0, ayanamsa);
+        spans.push(ElementSpan {
+            element,
+            index,
+            name,
+            start,
+            end,
+        });
+        if end >= to_jd {
+            return Ok(spans);
+        }
+        start = end;
+        // a tenth of a second past the change, well clear of its tolerance
+        jd = end + 1e-6;
+    }
+}
+
+/// Tithis of the Hindu day from `sunrise_jd` to `next_sunrise_jd`, marking
+/// the kshaya (skipped) tithis and whether the day's tithi is adhika
+/// (repeated).
+pub fn tithi_day(sunrise_jd: f64, next_sunrise_jd: f64, ayanamsa: Ayanamsa) -> Result<TithiDay> {
+    let tithis = element_spans(
+        PanchangaElement::Tithi,
+        sunrise_jd,
+        next_sunrise_jd,
+        ayanamsa,
+    )?;
+    let kshaya = tithis
+        .iter()
+        .filter(|span| span.start > sunrise_jd && span.end < next_sunrise_jd)
+        .map(|span| span.index)
+        .collect();
+    Ok(TithiDay {
+        sunrise: sunrise_jd,
+        next_sunrise: next_sunrise_jd,
+        adhika: tithis.len() == 1,
+        tithis,
+        kshaya,
+    })
+}
+
+/// Last new moon (Amavasya ending) at or before `jd_ut`.
+pub fn previous_new_moon(jd_ut: f64, ayanamsa: Ayanamsa) -> Result<f64> {
+    let element = PanchangaElement::Tithi;
+    let angle = element.angle(jd_ut, ayanamsa);
+    let new_moon = crossing(element, jd_ut - angle / ELONGATION_RATE, 0.0, ayanamsa)?;
+    if new_moon > jd_ut {
+        // the mean motion overshot a new moon only just past
+        return previous_new_moon(jd_ut - 1.0, ayanamsa);
+    }
+    Ok(new_moon)
+}
+
+/// First new moon after `jd_ut`.
+pub fn next_new_moon(jd_ut: f64, ayanamsa: Ayanamsa) -> Result<f64> {
+    let element = PanchangaElement::Tithi;
+    let angle = element.angle(jd_ut, ayanamsa);
+    let guess = jd_ut + (360.0 - angle) / ELONGATION_RATE;
+    let new_moon = crossing(element, guess, 0.0, ayanamsa)?;
+    if new_moon <= jd_ut {
+        return next_new_moon(jd_ut + 1.0, ayanamsa);
+    }
+    Ok(new_moon)
+}
+
+/// Instant near `guess` at which the element's angle equals `target_deg`.
+fn crossing(
+    element: PanchangaElement,
+    guess: f64,
+    target_deg: f64,
+    ayanamsa: Ayanamsa,
+) -> Result<f64> {
+    const STEP: f64 = 0.01;
+    let mut jd = guess;
+    for _ in 0..30 {
+        let error = signed_degrees(target_deg - element.angle(jd, ayanamsa));
+        if error.abs() < 1e-7 {
+            return Ok(jd);
+        }
+        let rate =
+            signed_degrees(element.angle(jd + STEP, ayanamsa) - element.angle(jd - STEP, ayanamsa))
+                / (2.0 * STEP);
+        jd += error / rate;
+    }
+    Err(VedicError::CalculationError(format!(
+        "{:?} boundary at {}° not found near JD {}",
+        element, target_deg, guess
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn tithi_end_matches_drik_panchang_for_delhi() {
+        // Krishna Panchami at the 07:14 IST sunrise of 2024 January 1, which
+        // Drik Panchang gives as ending at 14:29 IST (08:59 UT)
+        let sunrise = 2460310.5 + 1.733 / 24.0;
+        let span = element_at(PanchangaElement::Tithi, sunrise, Ayanamsa::Lahiri).unwrap();
+        assert_eq!((span.index, span.name.as_str()), (20, "Panchami"));
+        let drik = 2460310.5 + (8.0 + 59.0 / 60.0) / 24.0;
+        assert!((span.end - drik).abs() * 1440.0 < 2.0, "{}", span.end);
+
+        let ist = FixedOffset::east_opt(19800).unwrap();
+        let end = span.end_datetime(&ist).unwrap();
+        let published = ist.with_ymd_and_hms(2024, 1, 1, 14, 29, 0).unwrap();
+        assert!((end - published).num_seconds().abs() < 120, "{}", end);
+        assert!(span.start_datetime(&ist).unwrap() < end);
+    }
+}
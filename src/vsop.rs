@@ -0,0 +1,854 @@
+//! Planetary ephemeris from the abridged VSOP87D series of Meeus.
+//!
+//! The term tables are not the complete VSOP87 theory (Bretagnon & Francou,
+//! 1988) but the truncation printed in Meeus, *Astronomical Algorithms*,
+//! App. III: heliocentric longitude, latitude and radius of date, with 64
+//! terms in the Earth's L0 where the full series has 559. Against the
+//! reference values of `vsop87.chk` every planet comes out within 2″ at
+//! J2000, the Earth within 1″ and 10⁻⁵ AU in 1899 as well. The dropped terms
+//! grow with the powers of time they multiply, so the error grows away from
+//! J2000.
+//!
+//! The complete tables run to several megabytes of text and are not built
+//! in. Work needing better than about an arcsecond reads the published
+//! files (`VSOP87D.ear` and the rest, from the IMCCE) with
+//! [`Vsop87Series`], which evaluates every term of the theory.
+//!
+//! The heliocentric series live in one module per planet under `vsop/`; this
+//! module evaluates them and turns the resulting state vectors into the
+//! orbital elements consumed by `compute_planet_position`. The Moon is not
+//! part of VSOP87 and comes from the ELP2000-82 series in `elp`; the lunar
+//! nodes are taken from the mean orbit (Meeus, *Astronomical Algorithms*,
+//! ch. 47).
+//!
+//! Every `get_*` function returns elliptic elements in the VSOP87 layout
+//! `[a, λ, k, h, q, p]`, referred to the mean ecliptic and equinox of date:
+//!
+//! | index | element | meaning                   | unit |
+//! |-------|---------|---------------------------|------|
+//! | 0     | `a`     | semi-major axis           | AU   |
+//! | 1     | `λ`     | mean longitude            | rad  |
+//! | 2     | `k`     | `e · cos ϖ`               | –    |
+//! | 3     | `h`     | `e · sin ϖ`               | –    |
+//! | 4     | `q`     | `sin(i/2) · cos Ω`        | –    |
+//! | 5     | `p`     | `sin(i/2) · sin Ω`        | –    |
+//!
+//! [`vsop87`] gives the heliocentric spherical coordinates of the built-in
+//! series and their rates. The other VSOP87 versions (rectangular, J2000,
+//! barycentric or elliptic, listed by [`VsopVariant`]) are separate series
+//! of the theory, not transformations of D, and are only available from
+//! their published files through [`Vsop87Series`]. [`get_geocentric`] does
+//! the Earth subtraction that turns a heliocentric planet into the position
+//! seen from the Earth.
+//!
+//! All `jd` arguments are Julian Ephemeris Days, JD(TT); see [`crate::ut_to_tt`].
+
+use std::f64::consts::PI;
+
+use crate::elp::{mean_lunar_node, moon_spherical};
+use crate::{Ayanamsa, Result, VedicError, DEG_TO_RAD, J2000};
+
+mod earth;
+mod jupiter;
+mod mars;
+mod mercury;
+mod neptune;
+mod saturn;
+mod uranus;
+mod venus;
+
+/// One VSOP87 coordinate: a list of `[A, B, C]` terms for each power of τ.
+type Series = &'static [&'static [[f64; 3]]];
+
+/// The VSOP87 versions, named after the files of the theory. Each is a
+/// series of its own; [`Vsop87Series::evaluate`] returns the layout given
+/// here, with rates per day, angles in radians and distances in AU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VsopVariant {
+    /// VSOP87: heliocentric elliptic elements `[a, λ, k, h, q, p]`, J2000.
+    /// The Earth is tabulated as the Earth–Moon barycenter, `"EMB"`.
+    Elliptic,
+    /// VSOP87A: heliocentric rectangular `[x, y, z, ẋ, ẏ, ż]`, J2000.
+    A,
+    /// VSOP87B: heliocentric spherical `[L, B, R, L', B', R']`, J2000.
+    B,
+    /// VSOP87C: heliocentric rectangular `[x, y, z, ẋ, ẏ, ż]` of date.
+    C,
+    /// VSOP87D: heliocentric spherical `[L, B, R, L', B', R']` of date, the
+    /// version whose abridgement is built in.
+    D,
+    /// VSOP87E: barycentric rectangular `[x, y, z, ẋ, ẏ, ż]`, J2000.
+    E,
+}
+
+/// Gaussian gravitational constant, AU^(3/2) day^-1 M☉^(-1/2).
+const GAUSS_K: f64 = 0.01720209895;
+const DAYS_PER_MILLENNIUM: f64 = 365250.0;
+const DAYS_PER_CENTURY: f64 = 36525.0;
+const AU_KM: f64 = 149597870.7;
+/// Earth/Moon mass ratio, used to place the Earth–Moon barycenter.
+const EARTH_MOON_MASS_RATIO: f64 = 81.30056;
+
+struct Planet {
+    l: Series,
+    b: Series,
+    r: Series,
+    /// Sun/planet mass ratio.
+    inverse_mass: f64,
+}
+
+const MERCURY: Planet = Planet {
+    l: mercury::L,
+    b: mercury::B,
+    r: mercury::R,
+    inverse_mass: 6023600.0,
+};
+const VENUS: Planet = Planet {
+    l: venus::L,
+    b: venus::B,
+    r: venus::R,
+    inverse_mass: 408523.71,
+};
+const EARTH: Planet = Planet {
+    l: earth::L,
+    b: earth::B,
+    r: earth::R,
+    inverse_mass: 328900.56,
+};
+const MARS: Planet = Planet {
+    l: mars::L,
+    b: mars::B,
+    r: mars::R,
+    inverse_mass: 3098708.0,
+};
+const JUPITER: Planet = Planet {
+    l: jupiter::L,
+    b: jupiter::B,
+    r: jupiter::R,
+    inverse_mass: 1047.3486,
+};
+const SATURN: Planet = Planet {
+    l: saturn::L,
+    b: saturn::B,
+    r: saturn::R,
+    inverse_mass: 3497.898,
+};
+const URANUS: Planet = Planet {
+    l: uranus::L,
+    b: uranus::B,
+    r: uranus::R,
+    inverse_mass: 22902.98,
+};
+const NEPTUNE: Planet = Planet {
+    l: neptune::L,
+    b: neptune::B,
+    r: neptune::R,
+    inverse_mass: 19412.24,
+};
+
+const PLANETS: [(&str, &Planet); 8] = [
+    ("Mercury", &MERCURY),
+    ("Venus", &VENUS),
+    ("Earth", &EARTH),
+    ("Mars", &MARS),
+    ("Jupiter", &JUPITER),
+    ("Saturn", &SATURN),
+    ("Uranus", &URANUS),
+    ("Neptune", &NEPTUNE),
+];
+
+/// Evaluate one coordinate and its rate of change at `tau` Julian millennia
+/// from J2000. Returns (value, d/dτ) in the unit of the amplitudes.
+fn evaluate<T: AsRef<[[f64; 3]]>>(series: &[T], tau: f64) -> (f64, f64) {
+    let mut value = 0.0;
+    let mut rate = 0.0;
+
+    for (n, terms) in series.iter().enumerate() {
+        let mut sum = 0.0;
+        let mut sum_rate = 0.0;
+        for &[a, b, c] in terms.as_ref() {
+            let arg = b + c * tau;
+            sum += a * arg.cos();
+            sum_rate -= a * c * arg.sin();
+        }
+
+        let power = tau.powi(n as i32);
+        value += power * sum;
+        rate += power * sum_rate;
+        if n > 0 {
+            rate += n as f64 * tau.powi(n as i32 - 1) * sum;
+        }
+    }
+
+    (value, rate)
+}
+
+/// Heliocentric `[L, B, R, dL/dt, dB/dt, dR/dt]` of date, with rates per day.
+fn spherical_of_date(planet: &Planet, jd: f64) -> [f64; 6] {
+    let tau = (jd - J2000) / DAYS_PER_MILLENNIUM;
+    let (l, dl) = evaluate(planet.l, tau);
+    let (b, db) = evaluate(planet.b, tau);
+    let (r, dr) = evaluate(planet.r, tau);
+
+    // the abridged amplitudes are in units of 1e-8 rad and 1e-8 AU
+    let scale = 1e-8 / DAYS_PER_MILLENNIUM;
+    [
+        normalize_radians(l * 1e-8),
+        b * 1e-8,
+        r * 1e-8,
+        dl * scale,
+        db * scale,
+        dr * scale,
+    ]
+}
+
+/// Convert `[L, B, R, L', B', R']` into rectangular position and velocity.
+fn spherical_to_rectangular(s: [f64; 6]) -> ([f64; 3], [f64; 3]) {
+    let [l, b, r, dl, db, dr] = s;
+    let (sin_l, cos_l) = l.sin_cos();
+    let (sin_b, cos_b) = b.sin_cos();
+
+    let position = [r * cos_b * cos_l, r * cos_b * sin_l, r * sin_b];
+    let velocity = [
+        dr * cos_b * cos_l - r * sin_b * cos_l * db - r * cos_b * sin_l * dl,
+        dr * cos_b * sin_l - r * sin_b * sin_l * db + r * cos_b * cos_l * dl,
+        dr * sin_b + r * cos_b * db,
+    ];
+    (position, velocity)
+}
+
+/// Inverse of [`spherical_to_rectangular`].
+pub(crate) fn rectangular_to_spherical(position: [f64; 3], velocity: [f64; 3]) -> [f64; 6] {
+    let [x, y, z] = position;
+    let [vx, vy, vz] = velocity;
+    let rho2 = x * x + y * y;
+    let rho = rho2.sqrt();
+    let r = (rho2 + z * z).sqrt();
+
+    [
+        normalize_radians(y.atan2(x)),
+        z.atan2(rho),
+        r,
+        (x * vy - y * vx) / rho2,
+        (vz * rho2 - z * (x * vx + y * vy)) / (r * r * rho),
+        dot(position, velocity) / r,
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Osculating elements `[a, λ, k, h, q, p]` of a two-body orbit with
+/// gravitational parameter `mu` (AU³/day²).
+fn elements_from_state(position: [f64; 3], velocity: [f64; 3], mu: f64) -> [f64; 6] {
+    let r = dot(position, position).sqrt();
+    let a = 1.0 / (2.0 / r - dot(velocity, velocity) / mu);
+
+    // Orbit normal gives the inclination and node.
+    let angular_momentum = cross(position, velocity);
+    let h_norm = dot(angular_momentum, angular_momentum).sqrt();
+    let normal = angular_momentum.map(|c| c / h_norm);
+    let inclination = normal[2].clamp(-1.0, 1.0).acos();
+    let node = normal[0].atan2(-normal[1]);
+    let q = (inclination / 2.0).sin() * node.cos();
+    let p = (inclination / 2.0).sin() * node.sin();
+
+    // Eccentricity vector, rotated into the frame whose x-axis is the
+    // origin of ϖ (Rz(Ω) · Rx(-i) · Rz(-Ω)), gives k and h directly.
+    let v_cross_h = cross(velocity, angular_momentum);
+    let ecc: [f64; 3] = std::array::from_fn(|n| v_cross_h[n] / mu - position[n] / r);
+    let (sin_o, cos_o) = node.sin_cos();
+    let (sin_i, cos_i) = inclination.sin_cos();
+    let x1 = cos_o * ecc[0] + sin_o * ecc[1];
+    let y1 = -sin_o * ecc[0] + cos_o * ecc[1];
+    let y2 = cos_i * y1 + sin_i * ecc[2];
+    let k = cos_o * x1 - sin_o * y2;
+    let h = sin_o * x1 + cos_o * y2;
+
+    // Mean longitude from the eccentric anomaly.
+    let e = (k * k + h * h).sqrt();
+    let e_cos_e = 1.0 - r / a;
+    let e_sin_e = dot(position, velocity) / (mu * a).sqrt();
+    let ecc_anomaly = e_sin_e.atan2(e_cos_e);
+    let mean_anomaly = ecc_anomaly - e * ecc_anomaly.sin();
+    let lambda = normalize_radians(mean_anomaly + h.atan2(k));
+
+    [a, lambda, k, h, q, p]
+}
+
+fn gravitational_parameter(inverse_mass: f64) -> f64 {
+    GAUSS_K * GAUSS_K * (1.0 + 1.0 / inverse_mass)
+}
+
+/// Gravitational parameter (AU³/day²) of the two-body orbit behind the
+/// element sets returned for `body` by the `get_*` functions.
+pub(crate) fn orbit_gravitational_parameter(body: &str) -> f64 {
+    match body {
+        "Moon" => GAUSS_K * GAUSS_K / EARTH.inverse_mass,
+        "Sun" | "EMB" => gravitational_parameter(EARTH.inverse_mass),
+        _ => PLANETS
+            .iter()
+            .find(|(name, _)| *name == body)
+            .map_or(GAUSS_K * GAUSS_K, |(_, planet)| {
+                gravitational_parameter(planet.inverse_mass)
+            }),
+    }
+}
+
+fn planet_elements(planet: &Planet, jd: f64) -> [f64; 6] {
+    let (position, velocity) = spherical_to_rectangular(spherical_of_date(planet, jd));
+    elements_from_state(
+        position,
+        velocity,
+        gravitational_parameter(planet.inverse_mass),
+    )
+}
+
+/// Heliocentric elliptic elements of Mercury, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_mercury(jd: f64) -> [f64; 6] {
+    planet_elements(&MERCURY, jd)
+}
+
+/// Heliocentric elliptic elements of Venus, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_venus(jd: f64) -> [f64; 6] {
+    planet_elements(&VENUS, jd)
+}
+
+/// Heliocentric elliptic elements of Mars, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_mars(jd: f64) -> [f64; 6] {
+    planet_elements(&MARS, jd)
+}
+
+/// Heliocentric elliptic elements of Jupiter, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_jupiter(jd: f64) -> [f64; 6] {
+    planet_elements(&JUPITER, jd)
+}
+
+/// Heliocentric elliptic elements of Saturn, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_saturn(jd: f64) -> [f64; 6] {
+    planet_elements(&SATURN, jd)
+}
+
+/// Heliocentric elliptic elements of Uranus, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_uranus(jd: f64) -> [f64; 6] {
+    planet_elements(&URANUS, jd)
+}
+
+/// Heliocentric elliptic elements of Neptune, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+pub fn get_neptune(jd: f64) -> [f64; 6] {
+    planet_elements(&NEPTUNE, jd)
+}
+
+/// Heliocentric elliptic elements of the Earth–Moon barycenter,
+/// `[a, λ, k, h, q, p]`: semi-major axis (AU), mean longitude (rad),
+/// `e·cos ϖ`, `e·sin ϖ`, `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`.
+///
+/// The barycenter is placed 1/(1 + 81.30056) of the way from the Earth's
+/// VSOP87 position towards the Moon.
+pub fn get_emb(jd: f64) -> [f64; 6] {
+    let (position, velocity) = emb_state_of_date(jd);
+    elements_from_state(
+        position,
+        velocity,
+        gravitational_parameter(EARTH.inverse_mass),
+    )
+}
+
+/// Heliocentric rectangular state of the Earth–Moon barycenter of date.
+fn emb_state_of_date(jd: f64) -> ([f64; 3], [f64; 3]) {
+    let scale = 1.0 / (1.0 + EARTH_MOON_MASS_RATIO);
+
+    let (mut position, mut velocity) = spherical_to_rectangular(spherical_of_date(&EARTH, jd));
+    let (moon_p, moon_v) = spherical_to_rectangular(moon_geocentric(jd));
+    for n in 0..3 {
+        position[n] += moon_p[n] * scale;
+        velocity[n] += moon_v[n] * scale;
+    }
+    (position, velocity)
+}
+
+/// Heliocentric rectangular state of date of a VSOP87 body: one of the eight
+/// planets, `"Earth"` or `"EMB"`.
+pub(crate) fn heliocentric_state_of_date(body: &str, jd: f64) -> Result<([f64; 3], [f64; 3])> {
+    if body == "EMB" {
+        return Ok(emb_state_of_date(jd));
+    }
+    PLANETS
+        .iter()
+        .find(|(name, _)| *name == body)
+        .map(|(_, planet)| spherical_to_rectangular(spherical_of_date(planet, jd)))
+        .ok_or_else(|| VedicError::InvalidPlanet(body.to_string()))
+}
+
+/// Heliocentric `[L, B, R, L', B', R']` of a body from the built-in
+/// VSOP87D series: longitude and latitude of date (rad), radius (AU) and
+/// their rates per day.
+///
+/// `body` is one of `"Mercury"`, `"Venus"`, `"Earth"`, `"EMB"`, `"Mars"`,
+/// `"Jupiter"`, `"Saturn"`, `"Uranus"` or `"Neptune"`.
+pub fn vsop87(body: &str, jd: f64) -> Result<[f64; 6]> {
+    let (position, velocity) = heliocentric_state_of_date(body, jd)?;
+    Ok(rectangular_to_spherical(position, velocity))
+}
+
+/// One file of the complete VSOP87 theory as published by the IMCCE, such
+/// as `VSOP87D.ear` or `VSOP87A.jup`: every term of one body in one
+/// variant, where the built-in tables hold Meeus's abridgement.
+///
+/// The variant and the coordinate of each term are read from the digits
+/// that open its line and the body from the name in the block headers, so
+/// any of the published files can be given to [`Vsop87Series::parse`].
+#[derive(Debug, Clone)]
+pub struct Vsop87Series {
+    variant: VsopVariant,
+    body: String,
+    /// Terms `[A, B, C]` of each coordinate, by power of τ.
+    coordinates: Vec<Vec<Vec<[f64; 3]>>>,
+}
+
+impl Vsop87Series {
+    /// Read a series from the text of a published VSOP87 file.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut variant = None;
+        let mut body = None;
+        let mut coordinates: Vec<Vec<Vec<[f64; 3]>>> = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.first() {
+                None => continue,
+                Some(&"VSOP87") => {
+                    // "... VERSION A1    EARTH     VARIABLE 1 (XYZ) ..."
+                    let name = fields
+                        .iter()
+                        .position(|&field| field == "VARIABLE")
+                        .and_then(|n| n.checked_sub(1))
+                        .map(|n| fields[n]);
+                    body = name.map(body_name);
+                    continue;
+                }
+                Some(_) => {}
+            }
+
+            let bad_line = || {
+                VedicError::DataError(format!(
+                    "VSOP87 line {} not understood: {}",
+                    number + 1,
+                    line
+                ))
+            };
+            // version, body, coordinate and power of τ, one digit each
+            let code: Vec<usize> = line
+                .get(1..5)
+                .ok_or_else(bad_line)?
+                .chars()
+                .map(|c| c.to_digit(10).map(|d| d as usize))
+                .collect::<Option<_>>()
+                .ok_or_else(bad_line)?;
+            let term: Vec<f64> = fields[fields.len().saturating_sub(3)..]
+                .iter()
+                .map(|field| field.parse())
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|_| bad_line())?;
+            let (Some(line_variant), &[a, b, c], 1..) = (variant_of(code[0]), &term[..], code[2])
+            else {
+                return Err(bad_line());
+            };
+            if variant.is_some_and(|known| known != line_variant) {
+                return Err(bad_line());
+            }
+            variant = Some(line_variant);
+
+            let (coordinate, power) = (code[2] - 1, code[3]);
+            if coordinates.len() <= coordinate {
+                coordinates.resize(coordinate + 1, Vec::new());
+            }
+            let series = &mut coordinates[coordinate];
+            if series.len() <= power {
+                series.resize(power + 1, Vec::new());
+            }
+            series[power].push([a, b, c]);
+        }
+
+        match (variant, body) {
+            (Some(variant), Some(body)) => Ok(Self {
+                variant,
+                body,
+                coordinates,
+            }),
+            _ => Err(VedicError::DataError(
+                "no VSOP87 header or terms found".to_string(),
+            )),
+        }
+    }
+
+    /// Read a series from a published VSOP87 file on disk.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| VedicError::DataError(format!("{}: {}", path.display(), e)))?;
+        Self::parse(&text)
+    }
+
+    /// The variant the file belongs to.
+    pub fn variant(&self) -> VsopVariant {
+        self.variant
+    }
+
+    /// The body, named as for [`vsop87`]; `"Sun"` for the barycentric Sun
+    /// of VSOP87E.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// The body at `jd`, laid out as [`VsopVariant`] describes.
+    pub fn evaluate(&self, jd: f64) -> [f64; 6] {
+        let tau = (jd - J2000) / DAYS_PER_MILLENNIUM;
+        let coordinate = |n: usize| {
+            self.coordinates
+                .get(n)
+                .map_or((0.0, 0.0), |series| evaluate(series, tau))
+        };
+
+        let mut coords = [0.0; 6];
+        if self.variant == VsopVariant::Elliptic {
+            for (n, value) in coords.iter_mut().enumerate() {
+                *value = coordinate(n).0;
+            }
+            coords[1] = normalize_radians(coords[1]);
+        } else {
+            for n in 0..3 {
+                let (value, rate) = coordinate(n);
+                coords[n] = value;
+                coords[n + 3] = rate / DAYS_PER_MILLENNIUM;
+            }
+            if matches!(self.variant, VsopVariant::B | VsopVariant::D) {
+                coords[0] = normalize_radians(coords[0]);
+            }
+        }
+        coords
+    }
+}
+
+/// Variant numbered as in the first digit of a VSOP87 term.
+fn variant_of(digit: usize) -> Option<VsopVariant> {
+    [
+        VsopVariant::Elliptic,
+        VsopVariant::A,
+        VsopVariant::B,
+        VsopVariant::C,
+        VsopVariant::D,
+        VsopVariant::E,
+    ]
+    .get(digit)
+    .copied()
+}
+
+/// Body name of a VSOP87 header, as `"Earth"` for `EARTH`.
+fn body_name(header: &str) -> String {
+    match header {
+        "EMB" => "EMB".to_string(),
+        _ => {
+            let lower = header.to_lowercase();
+            let mut chars = lower.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        }
+    }
+}
+
+/// Geometric geocentric `[λ, β, Δ, λ', β', Δ']` of a VSOP87 planet, the
+/// Sun or the Moon: ecliptic longitude and latitude of date (rad), distance
+/// (AU) and their rates per day. For planets the Earth's heliocentric state
+/// is subtracted from the planet's (or negated, for `"Sun"`); the Moon is
+/// taken directly from ELP2000-82.
+pub fn get_geocentric(body: &str, jd: f64) -> Result<[f64; 6]> {
+    if body == "Moon" {
+        return Ok(moon_geocentric(jd));
+    }
+    let (earth_p, earth_v) = heliocentric_state_of_date("Earth", jd)?;
+    let (planet_p, planet_v) = if body == "Sun" {
+        ([0.0; 3], [0.0; 3])
+    } else {
+        heliocentric_state_of_date(body, jd)?
+    };
+
+    Ok(rectangular_to_spherical(
+        std::array::from_fn(|n| planet_p[n] - earth_p[n]),
+        std::array::from_fn(|n| planet_v[n] - earth_v[n]),
+    ))
+}
+
+/// Geocentric elliptic elements of the Sun's apparent orbit,
+/// `[a, λ, k, h, q, p]`: semi-major axis (AU), mean longitude (rad),
+/// `e·cos ϖ`, `e·sin ϖ`, `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`.
+///
+/// This is the Earth's VSOP87 orbit seen from the other end: λ and ϖ are
+/// shifted by 180°, the orbital plane is unchanged.
+pub fn get_sun(jd: f64) -> [f64; 6] {
+    let (position, velocity) = spherical_to_rectangular(spherical_of_date(&EARTH, jd));
+    elements_from_state(
+        position.map(|c| -c),
+        velocity.map(|c| -c),
+        gravitational_parameter(EARTH.inverse_mass),
+    )
+}
+
+/// Geocentric osculating elements of the Moon, `[a, λ, k, h, q, p]`:
+/// semi-major axis (AU), mean longitude (rad), `e·cos ϖ`, `e·sin ϖ`,
+/// `sin(i/2)·cos Ω` and `sin(i/2)·sin Ω`, ecliptic and equinox of date.
+///
+/// The elements are fitted to the ELP2000-82 position and velocity at `jd`,
+/// so solving Kepler's equation with them returns that position exactly.
+pub fn get_moon(jd: f64) -> [f64; 6] {
+    let (position, velocity) = spherical_to_rectangular(moon_geocentric(jd));
+    elements_from_state(position, velocity, orbit_gravitational_parameter("Moon"))
+}
+
+/// Geocentric `[λ, β, Δ, λ', β', Δ']` of the Moon from ELP2000-82, with the
+/// distance in AU and rates per day.
+fn moon_geocentric(jd: f64) -> [f64; 6] {
+    const STEP: f64 = 0.005;
+    let [lon, lat, dist] = moon_spherical(jd);
+    let ahead = moon_spherical(jd + STEP);
+    let behind = moon_spherical(jd - STEP);
+    let mut dlon = ahead[0] - behind[0];
+    if dlon < -PI {
+        dlon += 2.0 * PI;
+    }
+
+    [
+        normalize_radians(lon),
+        lat,
+        dist / AU_KM,
+        dlon / (2.0 * STEP),
+        (ahead[1] - behind[1]) / (2.0 * STEP),
+        (ahead[2] - behind[2]) / (2.0 * STEP) / AU_KM,
+    ]
+}
+
+/// Rahu (mean ascending lunar node) as a degenerate element set,
+/// `[0, Ω, 0, 0, 0, 0]`: zero distance, zero eccentricity and inclination,
+/// so the "mean longitude" at index 1 is the node's longitude in radians.
+pub fn get_rahu(jd: f64) -> [f64; 6] {
+    let t = (jd - J2000) / DAYS_PER_CENTURY;
+    [
+        0.0,
+        normalize_radians(mean_lunar_node(t) * DEG_TO_RAD),
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ]
+}
+
+/// Ketu (mean descending lunar node) as a degenerate element set,
+/// `[0, Ω + π, 0, 0, 0, 0]`, laid out like [`get_rahu`].
+pub fn get_ketu(jd: f64) -> [f64; 6] {
+    let mut ketu = get_rahu(jd);
+    ketu[1] = normalize_radians(ketu[1] + PI);
+    ketu
+}
+
+/// Lahiri ayanamsa in degrees; see [`Ayanamsa::Lahiri`].
+pub fn calculate_lahiri_ayanamsa(jd: f64) -> f64 {
+    Ayanamsa::Lahiri.degrees(jd)
+}
+
+/// True Chitrapaksha ayanamsa in degrees: Spica at 180°; see
+/// [`Ayanamsa::TrueChitra`].
+pub fn calculate_true_chitra_ayanamsa(jd: f64) -> f64 {
+    Ayanamsa::TrueChitra.degrees(jd)
+}
+
+/// True Pushya ayanamsa in degrees: δ Cancri at 106°; see
+/// [`Ayanamsa::TruePushya`].
+pub fn calculate_true_pushya_ayanamsa(jd: f64) -> f64 {
+    Ayanamsa::TruePushya.degrees(jd)
+}
+
+/// Default (Lahiri) ayanamsa in radians, as used by [`tropical_to_sidereal`].
+pub fn calculate_ayanamsa(jd: f64) -> f64 {
+    Ayanamsa::default().degrees(jd) * DEG_TO_RAD
+}
+
+/// Convert a tropical longitude in radians into a sidereal one in radians,
+/// with the default (Lahiri) ayanamsa.
+pub fn tropical_to_sidereal(tropical_long_rad: f64, jd: f64) -> f64 {
+    normalize_radians(tropical_long_rad - calculate_ayanamsa(jd))
+}
+
+/// Reduce an angle in degrees to [0, 360).
+pub fn normalize_degrees(degrees: f64) -> f64 {
+    let d = degrees.rem_euclid(360.0);
+    if d >= 360.0 {
+        0.0
+    } else {
+        d
+    }
+}
+
+/// Reduce an angle in radians to [0, 2π).
+pub fn normalize_radians(radians: f64) -> f64 {
+    let r = radians.rem_euclid(2.0 * PI);
+    if r >= 2.0 * PI {
+        0.0
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `[L, B, R]` of VSOP87D from `vsop87.chk`, against the abridged series.
+    fn check_d(body: &str, jd: f64, expected: [f64; 3]) {
+        let [l, b, r, ..] = vsop87(body, jd).unwrap();
+        assert!(
+            (l - expected[0]).abs() < 1e-5,
+            "{} L at {}: {}",
+            body,
+            jd,
+            l
+        );
+        assert!(
+            (b - expected[1]).abs() < 5e-6,
+            "{} B at {}: {}",
+            body,
+            jd,
+            b
+        );
+        assert!(
+            (r - expected[2]).abs() < 1e-5,
+            "{} R at {}: {}",
+            body,
+            jd,
+            r
+        );
+    }
+
+    #[test]
+    fn earth_matches_vsop87_check_values() {
+        check_d(
+            "Earth",
+            2451545.0,
+            [1.7519238681, -0.0000039656, 0.9833276819],
+        );
+        check_d(
+            "Earth",
+            2415020.0,
+            [1.7391225563, -0.0000005679, 0.9832689778],
+        );
+    }
+
+    #[test]
+    fn mars_matches_vsop87_check_values() {
+        check_d(
+            "Mars",
+            2451545.0,
+            [6.2735389983, -0.0247779824, 1.3912076925],
+        );
+    }
+
+    #[test]
+    fn every_planet_matches_vsop87_check_values_at_j2000() {
+        check_d(
+            "Venus",
+            2451545.0,
+            [3.1870221833, 0.0569782849, 0.7202129253],
+        );
+        check_d(
+            "Jupiter",
+            2451545.0,
+            [0.6334614186, -0.0205001039, 4.9653813154],
+        );
+        check_d(
+            "Neptune",
+            2451545.0,
+            [5.3045629252, 0.0042236789, 30.1205328392],
+        );
+        // Mercury, Saturn and Uranus by direction alone
+        for (body, l, b) in [
+            ("Mercury", 4.4293481036, -0.0527573409),
+            ("Saturn", 0.7980038761, -0.0401984149),
+            ("Uranus", 5.5225485803, -0.0119527838),
+        ] {
+            let [found_l, found_b, ..] = vsop87(body, 2451545.0).unwrap();
+            assert!((found_l - l).abs() < 1e-5, "{} L: {}", body, found_l);
+            assert!((found_b - b).abs() < 5e-6, "{} B: {}", body, found_b);
+        }
+    }
+
+    /// The opening terms of `VSOP87D.ear`, in the layout of the file.
+    const EARTH_D_EXCERPT: &str = "\
+ VSOP87 VERSION D4    EARTH     VARIABLE 1 (LBR)       *T**0      2 TERMS    HELIOCENTRIC DYNAMICAL ECLIPTIC AND EQUINOX OF THE DATE
+ 4310    1  0  0  0  0  0  0  0  0  0  0  0  0 -0.00000000000     1.75347045673     1.75347045673 0.00000000000      0.00000000000
+ 4310    2  0  0  1 -1  0  0  0  0  0  0  0  0  0.03338548555    -0.00144088229     0.03341656456 4.66925680417   6283.07584999140
+ VSOP87 VERSION D4    EARTH     VARIABLE 1 (LBR)       *T**1      1 TERMS    HELIOCENTRIC DYNAMICAL ECLIPTIC AND EQUINOX OF THE DATE
+ 4311    1  0  0  0  0  0  0  0  0  0  0  0  0 -0.00000000000  6283.31966747491  6283.31966747491 0.00000000000      0.00000000000
+ VSOP87 VERSION D4    EARTH     VARIABLE 2 (LBR)       *T**0      1 TERMS    HELIOCENTRIC DYNAMICAL ECLIPTIC AND EQUINOX OF THE DATE
+ 4320    1  0  0  0  0  0  1 -2  0  0  0  0  0  0.00000015960    -0.00000279164     0.00000279620 3.19870156017  84334.66158130829
+ VSOP87 VERSION D4    EARTH     VARIABLE 3 (LBR)       *T**0      1 TERMS    HELIOCENTRIC DYNAMICAL ECLIPTIC AND EQUINOX OF THE DATE
+ 4330    1  0  0  0  0  0  0  0  0  0  0  0  0 -0.00000000000     1.00013988784     1.00013988784 0.00000000000      0.00000000000
+";
+
+    #[test]
+    fn published_series_files_are_read_term_by_term() {
+        let series = Vsop87Series::parse(EARTH_D_EXCERPT).unwrap();
+        assert_eq!((series.variant(), series.body()), (VsopVariant::D, "Earth"));
+
+        let jd = 2451545.0 + 3652.5;
+        let tau: f64 = 0.01;
+        let [l, b, r, dl, ..] = series.evaluate(jd);
+        let l_expected = 1.75347045673
+            + 0.03341656456 * (4.66925680417 + 6283.07584999140 * tau).cos()
+            + 6283.31966747491 * tau;
+        let dl_expected =
+            (-0.03341656456 * 6283.07584999140 * (4.66925680417 + 6283.07584999140 * tau).sin()
+                + 6283.31966747491)
+                / 365250.0;
+        assert!((l - normalize_radians(l_expected)).abs() < 1e-12);
+        assert!((dl - dl_expected).abs() < 1e-12);
+        assert!(
+            (b - 0.00000279620 * (3.19870156017 + 84334.66158130829 * tau).cos()).abs() < 1e-15
+        );
+        assert_eq!(r, 1.00013988784);
+    }
+
+    #[test]
+    fn malformed_series_files_are_rejected() {
+        assert!(Vsop87Series::parse("").is_err());
+        let truncated: String = EARTH_D_EXCERPT
+            .lines()
+            .map(|line| format!("{}\n", line.get(..10).unwrap_or(line)))
+            .collect();
+        assert!(Vsop87Series::parse(&truncated).is_err());
+        let mixed = EARTH_D_EXCERPT.replace(" 4330", " 1330");
+        assert!(Vsop87Series::parse(&mixed).is_err());
+    }
+}